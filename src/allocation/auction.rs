@@ -0,0 +1,57 @@
+use std::collections::VecDeque;
+
+/// Enchères de Bertsekas : chaque robot libre enchérit sur la tâche qui lui rapporte
+/// le plus (valeur élevée pour un chemin court) compte tenu des prix courants.
+/// Un robot préfère rester libre plutôt que de payer plus que la tâche ne vaut.
+pub fn auction(costs: &[Vec<Option<u32>>]) -> Vec<(usize, usize)> {
+    let robot_count = costs.len();
+    let task_count = costs.first().map_or(0, |row| row.len());
+    if robot_count == 0 || task_count == 0 {
+        return Vec::new();
+    }
+
+    // Avec des coûts entiers multipliés par (n + 1) et un incrément de 1,
+    // l'affectation obtenue est optimale.
+    let scale = robot_count as i64 + 1;
+    let max_cost = costs.iter().flatten().flatten().copied().max().unwrap_or(0) as i64;
+    let value = |robot: usize, task: usize| -> Option<i64> {
+        costs[robot][task].map(|cost| (max_cost + 1 - cost as i64) * scale)
+    };
+
+    let mut prices = vec![0i64; task_count];
+    let mut owner: Vec<Option<usize>> = vec![None; task_count];
+    let mut unassigned: VecDeque<usize> = (0..robot_count).collect();
+
+    while let Some(robot) = unassigned.pop_front() {
+        let mut best: Option<(usize, i64)> = None;
+        let mut second = 0i64; // Rester libre ne rapporte rien
+
+        for (task, price) in prices.iter().enumerate() {
+            let Some(value) = value(robot, task) else { continue };
+            let profit = value - price;
+            match best {
+                Some((_, best_profit)) if profit <= best_profit => second = second.max(profit),
+                Some((_, best_profit)) => {
+                    second = second.max(best_profit);
+                    best = Some((task, profit));
+                }
+                None => best = Some((task, profit)),
+            }
+        }
+
+        let Some((task, best_profit)) = best else { continue };
+        if best_profit <= 0 {
+            continue;
+        }
+
+        prices[task] += best_profit - second + 1;
+        if let Some(previous) = owner[task].replace(robot) {
+            unassigned.push_back(previous);
+        }
+    }
+
+    owner.iter()
+        .enumerate()
+        .filter_map(|(task, robot)| robot.map(|robot| (robot, task)))
+        .collect()
+}
@@ -0,0 +1,26 @@
+/// Associe en priorité les paires robot/tâche les plus proches.
+pub fn greedy_nearest(costs: &[Vec<Option<u32>>]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<(u32, usize, usize)> = Vec::new();
+    for (robot, row) in costs.iter().enumerate() {
+        for (task, cost) in row.iter().enumerate() {
+            if let Some(cost) = cost {
+                pairs.push((*cost, robot, task));
+            }
+        }
+    }
+    pairs.sort();
+
+    let task_count = costs.first().map_or(0, |row| row.len());
+    let mut robot_taken = vec![false; costs.len()];
+    let mut task_taken = vec![false; task_count];
+    let mut result = Vec::new();
+
+    for (_, robot, task) in pairs {
+        if !robot_taken[robot] && !task_taken[task] {
+            robot_taken[robot] = true;
+            task_taken[task] = true;
+            result.push((robot, task));
+        }
+    }
+    result
+}
@@ -0,0 +1,88 @@
+// Coût utilisé pour les paires impossibles ; elles sont retirées du résultat
+const UNREACHABLE_COST: i64 = 1_000_000_000;
+
+/// Affectation de coût total minimal (méthode hongroise, O(n³)).
+/// La matrice est complétée en matrice carrée par des lignes ou colonnes fictives de coût nul.
+pub fn hungarian(costs: &[Vec<Option<u32>>]) -> Vec<(usize, usize)> {
+    let robot_count = costs.len();
+    let task_count = costs.first().map_or(0, |row| row.len());
+    let n = robot_count.max(task_count);
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let cost = |row: usize, column: usize| -> i64 {
+        if row >= robot_count || column >= task_count {
+            return 0;
+        }
+        costs[row][column].map_or(UNREACHABLE_COST, |cost| cost as i64)
+    };
+
+    // Potentiels et tableau d'appariement indexés à partir de 1 (0 = colonne fictive de départ)
+    let mut u = vec![0i64; n + 1];
+    let mut v = vec![0i64; n + 1];
+    let mut matched_row = vec![0usize; n + 1];
+    let mut way = vec![0usize; n + 1];
+
+    for row in 1..=n {
+        matched_row[0] = row;
+        let mut column0 = 0;
+        let mut min_value = vec![i64::MAX; n + 1];
+        let mut used = vec![false; n + 1];
+
+        loop {
+            used[column0] = true;
+            let row0 = matched_row[column0];
+            let mut delta = i64::MAX;
+            let mut column1 = 0;
+
+            for column in 1..=n {
+                if used[column] {
+                    continue;
+                }
+                let reduced = cost(row0 - 1, column - 1) - u[row0] - v[column];
+                if reduced < min_value[column] {
+                    min_value[column] = reduced;
+                    way[column] = column0;
+                }
+                if min_value[column] < delta {
+                    delta = min_value[column];
+                    column1 = column;
+                }
+            }
+
+            for column in 0..=n {
+                if used[column] {
+                    u[matched_row[column]] += delta;
+                    v[column] -= delta;
+                } else {
+                    min_value[column] -= delta;
+                }
+            }
+
+            column0 = column1;
+            if matched_row[column0] == 0 {
+                break;
+            }
+        }
+
+        loop {
+            let column1 = way[column0];
+            matched_row[column0] = matched_row[column1];
+            column0 = column1;
+            if column0 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut result: Vec<(usize, usize)> = (1..=n)
+        .filter_map(|column| {
+            let (robot, task) = (matched_row[column] - 1, column - 1);
+            let real = robot < robot_count && task < task_count;
+            (real && costs[robot][task].is_some()).then_some((robot, task))
+        })
+        .collect();
+    result.sort();
+    result
+}
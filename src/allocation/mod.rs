@@ -0,0 +1,8 @@
+pub mod auction;
+pub mod greedy;
+pub mod hungarian;
+pub mod strategy;
+#[cfg(test)]
+mod tests;
+
+pub use strategy::{AllocationMetrics, AllocationStrategy, allocate};
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use super::auction::auction;
+use super::greedy::greedy_nearest;
+use super::hungarian::hungarian;
+
+/// Méthode utilisée pour associer les robots libres aux ressources découvertes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllocationStrategy {
+    Greedy,
+    Auction,
+    Hungarian,
+}
+
+impl AllocationStrategy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AllocationStrategy::Greedy => "Glouton",
+            AllocationStrategy::Auction => "Enchères",
+            AllocationStrategy::Hungarian => "Hongroise",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            AllocationStrategy::Greedy => AllocationStrategy::Auction,
+            AllocationStrategy::Auction => AllocationStrategy::Hungarian,
+            AllocationStrategy::Hungarian => AllocationStrategy::Greedy,
+        }
+    }
+}
+
+/// Coût des affectations réalisées pendant un tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationMetrics {
+    pub tick: u64,
+    pub strategy: AllocationStrategy,
+    pub assignments: usize,
+    pub total_cost: u64,
+}
+
+impl AllocationMetrics {
+    pub fn new(tick: u64, strategy: AllocationStrategy) -> Self {
+        Self { tick, strategy, assignments: 0, total_cost: 0 }
+    }
+
+    pub fn record(&mut self, cost: u32) {
+        self.assignments += 1;
+        self.total_cost += cost as u64;
+    }
+}
+
+/// Calcule les paires (robot, tâche) à partir d'une matrice de coûts
+/// `costs[robot][tâche]`, `None` signifiant que la tâche est hors de portée du robot.
+/// Chaque robot et chaque tâche apparaissent au plus une fois dans le résultat.
+pub fn allocate(strategy: AllocationStrategy, costs: &[Vec<Option<u32>>]) -> Vec<(usize, usize)> {
+    match strategy {
+        AllocationStrategy::Greedy => greedy_nearest(costs),
+        AllocationStrategy::Auction => auction(costs),
+        AllocationStrategy::Hungarian => hungarian(costs),
+    }
+}
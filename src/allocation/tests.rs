@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod allocation_tests {
+    use super::super::{allocate, AllocationStrategy};
+
+    const STRATEGIES: [AllocationStrategy; 3] = [
+        AllocationStrategy::Greedy,
+        AllocationStrategy::Auction,
+        AllocationStrategy::Hungarian,
+    ];
+
+    fn total_cost(costs: &[Vec<Option<u32>>], pairs: &[(usize, usize)]) -> u32 {
+        pairs.iter().map(|&(robot, task)| costs[robot][task].unwrap()).sum()
+    }
+
+    #[test]
+    fn test_nearest_pairs() {
+        let costs = vec![
+            vec![Some(1), Some(9)],
+            vec![Some(9), Some(1)],
+        ];
+
+        for strategy in STRATEGIES {
+            let mut pairs = allocate(strategy, &costs);
+            pairs.sort();
+            assert_eq!(pairs, vec![(0, 0), (1, 1)], "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn test_optimal_beats_greedy() {
+        // Le glouton prend (0, 0) à coût 1 et laisse 100 au robot 1 ;
+        // l'optimum est 2 + 2.
+        let costs = vec![
+            vec![Some(1), Some(2)],
+            vec![Some(2), Some(100)],
+        ];
+
+        let greedy = allocate(AllocationStrategy::Greedy, &costs);
+        let auction = allocate(AllocationStrategy::Auction, &costs);
+        let hungarian = allocate(AllocationStrategy::Hungarian, &costs);
+
+        assert_eq!(total_cost(&costs, &greedy), 101);
+        assert_eq!(total_cost(&costs, &auction), 4);
+        assert_eq!(total_cost(&costs, &hungarian), 4);
+    }
+
+    #[test]
+    fn test_unreachable_tasks_are_skipped() {
+        let costs = vec![
+            vec![None, Some(3)],
+            vec![None, Some(5)],
+        ];
+
+        for strategy in STRATEGIES {
+            let pairs = allocate(strategy, &costs);
+            assert_eq!(pairs, vec![(0, 1)], "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn test_more_robots_than_tasks() {
+        let costs = vec![
+            vec![Some(7)],
+            vec![Some(2)],
+            vec![Some(4)],
+        ];
+
+        for strategy in STRATEGIES {
+            assert_eq!(allocate(strategy, &costs), vec![(1, 0)], "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn test_more_tasks_than_robots() {
+        let costs = vec![vec![Some(6), Some(2), Some(9)]];
+
+        for strategy in STRATEGIES {
+            assert_eq!(allocate(strategy, &costs), vec![(0, 1)], "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn test_empty_inputs() {
+        for strategy in STRATEGIES {
+            assert!(allocate(strategy, &[]).is_empty());
+            assert!(allocate(strategy, &[vec![], vec![]]).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_strategy_cycle() {
+        let mut strategy = AllocationStrategy::Greedy;
+        for _ in 0..3 {
+            strategy = strategy.next();
+        }
+        assert_eq!(strategy, AllocationStrategy::Greedy);
+    }
+}
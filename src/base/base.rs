@@ -1,12 +1,23 @@
 use std::collections::HashMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
 use crate::map::tile::Tile;
 use crate::robot::{Robot, RobotType};
+use super::fabrication::{FabricationError, ProductionQueue, Recipe};
+use super::upkeep::MILLI_ENERGY;
 
-#[derive(Clone, Debug)]
+// Écart entre deux quais le long du bord de la base
+const DOCK_SPACING: usize = 3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Base {
     pub x: usize,
     pub y: usize,
+    pub size: usize, // Côté de l'emprise carrée de la base
+    pub docking_slots: Vec<(usize, usize)>, // Quais de déchargement, sur le bord de l'emprise
     pub inventory: HashMap<Tile, u32>,
+    pub production: ProductionQueue,
+    upkeep_owed: u32, // Entretien dû, en millièmes d'Énergie, au plus une unité
 }
 
 impl Base {
@@ -20,7 +31,40 @@ impl Base {
         inventory.insert(Tile::Energy, 0);
         inventory.insert(Tile::Science, 0);
         
-        Self { x, y, inventory }
+        let docking_slots = Self::docking_slots_for(x, y, size);
+
+        Self { x, y, size, docking_slots, inventory, production: ProductionQueue::new(), upkeep_owed: 0 }
+    }
+
+    /// Vrai si (x, y) fait partie de l'emprise de la base.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x < self.x + self.size && y >= self.y && y < self.y + self.size
+    }
+
+    /// Distance (en cases, diagonales comprises) entre (x, y) et l'emprise de la base.
+    pub fn distance_to(&self, x: usize, y: usize) -> usize {
+        let axis = |value: usize, start: usize| {
+            if value < start {
+                start - value
+            } else {
+                value.saturating_sub(start + self.size - 1)
+            }
+        };
+        axis(x, self.x).max(axis(y, self.y))
+    }
+
+    /// Une case sur `DOCK_SPACING` du bord de l'emprise, dans l'ordre de lecture.
+    fn docking_slots_for(x0: usize, y0: usize, size: usize) -> Vec<(usize, usize)> {
+        let mut slots = Vec::new();
+        for y in y0..y0 + size {
+            for x in x0..x0 + size {
+                let on_edge = x == x0 || y == y0 || x + 1 == x0 + size || y + 1 == y0 + size;
+                if on_edge && (x + y).is_multiple_of(DOCK_SPACING) {
+                    slots.push((x, y));
+                }
+            }
+        }
+        slots
     }
     
     pub fn add_resource(&mut self, resource: Tile) {
@@ -30,9 +74,68 @@ impl Base {
     pub fn get_resources(&self) -> &HashMap<Tile, u32> {
         &self.inventory
     }
+
+    pub fn can_afford(&self, cost: &[(Tile, u32)]) -> bool {
+        cost.iter().all(|(resource, amount)| self.inventory.get(resource).unwrap_or(&0) >= amount)
+    }
+
+    /// Retire le coût du stock ; ne retire rien et renvoie faux si le stock ne suffit pas.
+    pub fn spend(&mut self, cost: &[(Tile, u32)]) -> bool {
+        if !self.can_afford(cost) {
+            return false;
+        }
+        for (resource, amount) in cost {
+            *self.inventory.entry(*resource).or_insert(0) -= amount;
+        }
+        true
+    }
+
+    /// Remet des ressources dans le stock, par exemple une commande annulée.
+    pub fn refund(&mut self, cost: &[(Tile, u32)]) {
+        for (resource, amount) in cost {
+            *self.inventory.entry(*resource).or_insert(0) += amount;
+        }
+    }
+
+    /// Ajoute l'entretien du tick (en millièmes d'Énergie) et paie les unités entières dues.
+    /// Faute de stock, le retard ne dépasse pas une unité : la base est alors en délestage.
+    pub fn pay_upkeep(&mut self, cost: u32) {
+        let energy = self.inventory.entry(Tile::Energy).or_insert(0);
+        self.upkeep_owed = self.upkeep_owed.saturating_add(cost);
+        let paid = (self.upkeep_owed / MILLI_ENERGY).min(*energy);
+        *energy -= paid;
+        self.upkeep_owed = (self.upkeep_owed - paid * MILLI_ENERGY).min(MILLI_ENERGY);
+    }
+
+    /// Vrai quand une unité d'entretien n'a pas pu être payée.
+    pub fn is_browned_out(&self) -> bool {
+        self.upkeep_owed >= MILLI_ENERGY
+    }
+
+    /// Commande un robot : paie la recette et l'ajoute à la file de production.
+    pub fn queue_robot(&mut self, robot_type: RobotType) -> Result<(), FabricationError> {
+        if self.production.is_full() {
+            return Err(FabricationError::QueueFull);
+        }
+        if !self.spend(&Recipe::for_type(robot_type).cost) {
+            return Err(FabricationError::InsufficientResources);
+        }
+        self.production.push(robot_type);
+        Ok(())
+    }
+}
+
+/// La carte ne contient aucune case de base où faire apparaître les robots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoBaseError;
+
+impl fmt::Display for NoBaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "aucune base trouvée sur la carte")
+    }
 }
 
-pub fn find_all_base_positions(map: &[Vec<Tile>]) -> Vec<(usize, usize)> {
+pub fn find_all_base_positions(map: &[Vec<Tile>]) -> Result<Vec<(usize, usize)>, NoBaseError> {
     let mut base_positions = Vec::new();
     for (y, row) in map.iter().enumerate() {
         for (x, tile) in row.iter().enumerate() {
@@ -42,9 +145,9 @@ pub fn find_all_base_positions(map: &[Vec<Tile>]) -> Vec<(usize, usize)> {
         }
     }
     if base_positions.is_empty() {
-        panic!("Aucune base trouvée sur la carte !");
+        return Err(NoBaseError);
     }
-    base_positions
+    Ok(base_positions)
 }
 
 pub fn spawn_robots_in_base(
@@ -54,28 +157,20 @@ pub fn spawn_robots_in_base(
     let mut robots = Vec::new();
     let mut position_index = 0;
 
-    println!("Positions de base disponibles: {}", base_positions.len());
-
     for (robot_type, count) in robot_counts {
         for _i in 0..*count {
             if position_index < base_positions.len() {
                 let (x, y) = base_positions[position_index];
                 robots.push(Robot::new(x, y, *robot_type));
-                println!("Robot créé: {:?} à la position de base ({}, {})", robot_type, x, y);
                 position_index += 1;
             } else {
                 position_index = 0;
                 let (x, y) = base_positions[position_index];
                 robots.push(Robot::new(x, y, *robot_type));
-                println!("Robot créé: {:?} à la position de base ({}, {}) - Position réutilisée", robot_type, x, y);
                 position_index += 1;
             }
         }
     }
 
-    println!("Total des robots créés: {} dans {} positions de base", 
-             robots.len(), 
-             base_positions.len());
-
     robots
 }
\ No newline at end of file
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+
+/// Point de dépôt éloigné de la base : les collecteurs y déchargent,
+/// les transporteurs rapportent son stock à la base.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Depot {
+    pub x: usize,
+    pub y: usize,
+    stock: BTreeMap<Tile, u32>,
+}
+
+impl Depot {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y, stock: BTreeMap::new() }
+    }
+
+    pub fn deposit(&mut self, resource: Tile) {
+        *self.stock.entry(resource).or_insert(0) += 1;
+    }
+
+    pub fn total(&self) -> u32 {
+        self.stock.values().sum()
+    }
+
+    pub fn stock(&self) -> &BTreeMap<Tile, u32> {
+        &self.stock
+    }
+
+    /// Retire jusqu'à `max` ressources du stock, dans l'ordre des types.
+    pub fn take(&mut self, max: u32) -> Vec<Tile> {
+        let mut taken = Vec::new();
+        for (resource, amount) in self.stock.iter_mut() {
+            while *amount > 0 && (taken.len() as u32) < max {
+                *amount -= 1;
+                taken.push(*resource);
+            }
+        }
+        self.stock.retain(|_, amount| *amount > 0);
+        taken
+    }
+}
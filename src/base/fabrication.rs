@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+use crate::robot::RobotType;
+
+// Nombre maximal de robots en attente dans la file de production
+pub const MAX_QUEUE_LENGTH: usize = 8;
+
+/// Coût et durée de fabrication d'un type de robot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recipe {
+    pub cost: Vec<(Tile, u32)>,
+    pub build_ticks: u32,
+}
+
+impl Recipe {
+    pub fn for_type(robot_type: RobotType) -> Self {
+        let (cost, build_ticks): (&[(Tile, u32)], u32) = match robot_type {
+            RobotType::Explorer => (&[(Tile::Mineral, 2), (Tile::Energy, 3)], 20),
+            RobotType::Miner => (&[(Tile::Mineral, 5), (Tile::Energy, 3)], 30),
+            RobotType::EnergyCollector => (&[(Tile::Mineral, 4), (Tile::Energy, 2)], 25),
+            RobotType::Scientist => (&[(Tile::Mineral, 4), (Tile::Energy, 2), (Tile::Science, 2)], 35),
+            RobotType::Rescue => (&[(Tile::Mineral, 6), (Tile::Energy, 4)], 40),
+            RobotType::Builder => (&[(Tile::Mineral, 6), (Tile::Energy, 2)], 35),
+            RobotType::Hauler => (&[(Tile::Mineral, 6), (Tile::Energy, 4)], 45),
+            RobotType::Relay => (&[(Tile::Mineral, 4), (Tile::Energy, 3), (Tile::Science, 2)], 40),
+        };
+        Self { cost: cost.to_vec(), build_ticks }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FabricationError {
+    QueueFull,
+    InsufficientResources,
+}
+
+impl fmt::Display for FabricationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FabricationError::QueueFull => write!(f, "file de production pleine"),
+            FabricationError::InsufficientResources => write!(f, "ressources insuffisantes"),
+        }
+    }
+}
+
+/// File des robots commandés ; seul le premier est en cours de fabrication.
+/// Les ressources sont prélevées au moment de la commande.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProductionQueue {
+    orders: VecDeque<RobotType>,
+    progress: u32,
+}
+
+impl ProductionQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.orders.len() >= MAX_QUEUE_LENGTH
+    }
+
+    pub fn orders(&self) -> impl Iterator<Item = &RobotType> {
+        self.orders.iter()
+    }
+
+    /// Nombre de robots de ce type commandés, celui en cours de fabrication compris.
+    pub fn count(&self, robot_type: RobotType) -> usize {
+        self.orders.iter().filter(|&&order| order == robot_type).count()
+    }
+
+    pub fn push(&mut self, robot_type: RobotType) {
+        self.orders.push_back(robot_type);
+    }
+
+    /// Robot en cours de fabrication, avec (ticks écoulés, ticks nécessaires).
+    pub fn current(&self) -> Option<(RobotType, u32, u32)> {
+        self.orders.front()
+            .map(|&robot_type| (robot_type, self.progress, Recipe::for_type(robot_type).build_ticks))
+    }
+
+    /// Fait avancer la fabrication d'un tick ; renvoie le type du robot terminé.
+    pub fn advance(&mut self) -> Option<RobotType> {
+        let (robot_type, _, build_ticks) = self.current()?;
+        self.progress += 1;
+        if self.progress < build_ticks {
+            return None;
+        }
+        self.progress = 0;
+        self.orders.pop_front();
+        Some(robot_type)
+    }
+}
+
+/// Politique de construction automatique : commande le type le plus en retard
+/// sur les proportions voulues, une commande par tick tant que la file n'est pas pleine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutobuildPolicy {
+    ratios: Vec<(RobotType, u32)>,
+}
+
+impl AutobuildPolicy {
+    pub fn new(ratios: Vec<(RobotType, u32)>) -> Self {
+        Self { ratios }
+    }
+
+    pub fn ratios(&self) -> &[(RobotType, u32)] {
+        &self.ratios
+    }
+
+    /// Type dont l'effectif rapporté à sa proportion est le plus faible.
+    /// `count` donne l'effectif actuel d'un type, commandes comprises.
+    pub fn next_type(&self, count: impl Fn(RobotType) -> usize) -> Option<RobotType> {
+        self.ratios.iter()
+            .filter(|(_, ratio)| *ratio > 0)
+            // Comparaison de count_a / ratio_a et count_b / ratio_b sans division
+            .min_by(|(type_a, ratio_a), (type_b, ratio_b)| {
+                (count(*type_a) * *ratio_b as usize).cmp(&(count(*type_b) * *ratio_a as usize))
+            })
+            .map(|(robot_type, _)| *robot_type)
+    }
+}
+
+impl Default for AutobuildPolicy {
+    /// Mêmes proportions que l'essaim de départ.
+    fn default() -> Self {
+        Self::new(vec![
+            (RobotType::Explorer, 4),
+            (RobotType::Miner, 3),
+            (RobotType::EnergyCollector, 2),
+            (RobotType::Scientist, 2),
+            (RobotType::Rescue, 1),
+            (RobotType::Builder, 1),
+        ])
+    }
+}
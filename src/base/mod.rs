@@ -1,5 +1,11 @@
 pub mod base;
+pub mod depot;
+pub mod fabrication;
+pub mod upkeep;
 #[cfg(test)]
 mod tests;
 
-pub use base::{Base, find_all_base_positions, spawn_robots_in_base};
\ No newline at end of file
+pub use base::{Base, NoBaseError, find_all_base_positions, spawn_robots_in_base};
+pub use depot::Depot;
+pub use fabrication::{AutobuildPolicy, FabricationError, ProductionQueue, Recipe};
+pub use upkeep::{MAX_UPKEEP, MILLI_ENERGY, Upkeep};
\ No newline at end of file
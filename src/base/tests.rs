@@ -1,6 +1,12 @@
 #[cfg(test)]
 mod base_tests {
-    use super::super::base::{Base, find_all_base_positions, spawn_robots_in_base};
+    use super::super::base::{Base, NoBaseError, find_all_base_positions, spawn_robots_in_base};
+    use super::super::depot::Depot;
+    use super::super::fabrication::{AutobuildPolicy, FabricationError, Recipe};
+    use super::super::upkeep::{MILLI_ENERGY, Upkeep};
+    use crate::config::{BaseSettings, ResourceAmounts, WorldConfig};
+    use crate::events::GameEvent;
+    use crate::game::GameState;
     use crate::map::tile::Tile;
     use crate::robot::RobotType;
 
@@ -23,6 +29,21 @@ mod base_tests {
         assert_eq!(base.y, 6);
     }
 
+    #[test]
+    fn test_base_footprint_and_docks() {
+        let base = Base::new(100, 80);
+
+        assert_eq!(base.size, 10);
+        assert!(base.contains(45, 35));
+        assert!(base.contains(54, 44));
+        assert!(!base.contains(55, 44));
+        assert!(!base.docking_slots.is_empty());
+        for &(x, y) in &base.docking_slots {
+            assert!(base.contains(x, y));
+            assert!(x == 45 || x == 54 || y == 35 || y == 44);
+        }
+    }
+
     #[test]
     fn test_add_resources() {
         let mut base = Base::new(50, 50);
@@ -36,6 +57,113 @@ mod base_tests {
         assert_eq!(base.inventory[&Tile::Science], 0);
     }
 
+    #[test]
+    fn test_spend_resources() {
+        let mut base = Base::new(50, 50);
+        base.add_resource(Tile::Energy);
+        base.add_resource(Tile::Energy);
+        base.add_resource(Tile::Mineral);
+
+        assert!(base.can_afford(&[(Tile::Energy, 2), (Tile::Mineral, 1)]));
+        assert!(!base.spend(&[(Tile::Energy, 1), (Tile::Science, 1)]));
+        assert_eq!(base.inventory[&Tile::Energy], 2);
+
+        assert!(base.spend(&[(Tile::Energy, 2)]));
+        assert_eq!(base.inventory[&Tile::Energy], 0);
+        assert_eq!(base.inventory[&Tile::Mineral], 1);
+    }
+
+    #[test]
+    fn test_queue_robot_spends_recipe() {
+        let mut base = Base::new(50, 50);
+        base.inventory.insert(Tile::Mineral, 6);
+        base.inventory.insert(Tile::Energy, 3);
+
+        assert_eq!(base.queue_robot(RobotType::Miner), Ok(()));
+        assert_eq!(base.inventory[&Tile::Mineral], 1);
+        assert_eq!(base.inventory[&Tile::Energy], 0);
+        assert_eq!(base.queue_robot(RobotType::Miner), Err(FabricationError::InsufficientResources));
+        assert_eq!(base.production.len(), 1);
+        assert_eq!(base.production.count(RobotType::Miner), 1);
+        assert_eq!(base.production.count(RobotType::Explorer), 0);
+    }
+
+    #[test]
+    fn test_production_takes_build_time() {
+        let mut base = Base::new(50, 50);
+        base.inventory.insert(Tile::Mineral, 10);
+        base.inventory.insert(Tile::Energy, 10);
+        base.queue_robot(RobotType::Explorer).unwrap();
+
+        let build_ticks = Recipe::for_type(RobotType::Explorer).build_ticks;
+        for _ in 1..build_ticks {
+            assert_eq!(base.production.advance(), None);
+        }
+        assert_eq!(base.production.advance(), Some(RobotType::Explorer));
+        assert!(base.production.is_empty());
+    }
+
+    #[test]
+    fn test_autobuild_picks_most_lacking_type() {
+        let policy = AutobuildPolicy::new(vec![(RobotType::Explorer, 2), (RobotType::Miner, 1)]);
+
+        let next = policy.next_type(|robot_type| match robot_type {
+            RobotType::Explorer => 3,
+            _ => 2,
+        });
+        assert_eq!(next, Some(RobotType::Explorer));
+
+        let next = policy.next_type(|robot_type| match robot_type {
+            RobotType::Explorer => 4,
+            _ => 1,
+        });
+        assert_eq!(next, Some(RobotType::Miner));
+
+        // À égalité, le premier type de la liste l'emporte
+        let next = policy.next_type(|robot_type| match robot_type {
+            RobotType::Explorer => 4,
+            _ => 2,
+        });
+        assert_eq!(next, Some(RobotType::Explorer));
+    }
+
+    #[test]
+    fn test_autobuild_counts_pending_orders() {
+        let config = WorldConfig {
+            width: 40,
+            height: 40,
+            robots: vec![(RobotType::Explorer, 1)],
+            base: BaseSettings {
+                starting_resources: ResourceAmounts { energy: 100, mineral: 100, science: 0 },
+                upkeep: Upkeep { life_support: 0, per_robot: 0 },
+                ..BaseSettings::default()
+            },
+            ..WorldConfig::default()
+        };
+        let mut game = GameState::with_config(&config).unwrap();
+        game.set_autobuild(Some(AutobuildPolicy::new(vec![(RobotType::Explorer, 1), (RobotType::Miner, 1)])));
+        game.update();
+        game.update();
+
+        // Le mineur en attente compte : la deuxième commande rééquilibre vers l'explorateur
+        let orders: Vec<RobotType> = game.get_production_queue().orders().copied().collect();
+        assert_eq!(orders, vec![RobotType::Miner, RobotType::Explorer]);
+    }
+
+    #[test]
+    fn test_depot_stock() {
+        let mut depot = Depot::new(3, 4);
+        depot.deposit(Tile::Energy);
+        depot.deposit(Tile::Mineral);
+        depot.deposit(Tile::Mineral);
+        assert_eq!(depot.total(), 3);
+
+        assert_eq!(depot.take(2), vec![Tile::Energy, Tile::Mineral]);
+        assert_eq!(depot.take(5), vec![Tile::Mineral]);
+        assert_eq!(depot.total(), 0);
+        assert!(depot.stock().is_empty());
+    }
+
     #[test]
     fn test_find_base_positions() {
         let map = vec![
@@ -44,7 +172,7 @@ mod base_tests {
             vec![Tile::Base, Tile::Empty, Tile::Base],
         ];
         
-        let positions = find_all_base_positions(&map);
+        let positions = find_all_base_positions(&map).unwrap();
         
         assert_eq!(positions.len(), 3);
         assert!(positions.contains(&(1, 0)));
@@ -53,14 +181,13 @@ mod base_tests {
     }
 
     #[test]
-    #[should_panic(expected = "Aucune base trouvée sur la carte !")]
-    fn test_no_base_panic() {
+    fn test_no_base_error() {
         let map = vec![
             vec![Tile::Empty, Tile::Empty],
             vec![Tile::Empty, Tile::Empty],
         ];
         
-        find_all_base_positions(&map);
+        assert_eq!(find_all_base_positions(&map), Err(NoBaseError));
     }
 
     #[test]
@@ -78,4 +205,73 @@ mod base_tests {
         assert_eq!(robots[1].robot_type, RobotType::Explorer);
         assert_eq!(robots[2].robot_type, RobotType::Miner);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_pay_upkeep() {
+        let mut base = Base::new(100, 80);
+        base.inventory.insert(Tile::Energy, 1);
+
+        // Les millièmes s'accumulent jusqu'à une unité entière
+        base.pay_upkeep(MILLI_ENERGY / 2);
+        assert_eq!(base.get_resources()[&Tile::Energy], 1);
+        base.pay_upkeep(MILLI_ENERGY / 2);
+        assert_eq!(base.get_resources()[&Tile::Energy], 0);
+        assert!(!base.is_browned_out());
+
+        // Sans stock, le retard plafonne à une unité, payée dès la livraison suivante
+        for _ in 0..5 {
+            base.pay_upkeep(MILLI_ENERGY);
+        }
+        assert!(base.is_browned_out());
+        base.add_resource(Tile::Energy);
+        base.add_resource(Tile::Energy);
+        base.pay_upkeep(0);
+        assert!(!base.is_browned_out());
+        assert_eq!(base.get_resources()[&Tile::Energy], 1);
+
+        // Le retard reste plafonné même pour un coût démesuré
+        base.pay_upkeep(u32::MAX);
+        base.pay_upkeep(u32::MAX);
+        assert!(base.is_browned_out());
+    }
+
+    #[test]
+    fn test_upkeep_cost() {
+        assert_eq!(Upkeep::default().cost(14), 24);
+        assert_eq!(Upkeep { life_support: 1, per_robot: u32::MAX }.cost(2), u32::MAX);
+    }
+
+    #[test]
+    fn test_brownout_halts_production() {
+        let config = WorldConfig {
+            width: 40,
+            height: 40,
+            base: BaseSettings {
+                starting_resources: ResourceAmounts { energy: 5, mineral: 10, science: 0 },
+                upkeep: Upkeep { life_support: MILLI_ENERGY, per_robot: 0 },
+                ..BaseSettings::default()
+            },
+            ..WorldConfig::default()
+        };
+        let mut game = GameState::with_config(&config).unwrap();
+        let receiver = game.subscribe();
+        game.queue_robot(RobotType::Explorer).unwrap();
+        for _ in 0..3 {
+            game.update();
+        }
+        assert!(game.is_browned_out());
+        assert!(game.get_energy_flow() < 0.0);
+
+        // La fabrication reste figée tant que dure le délestage
+        let progress = game.get_production_queue().current();
+        for _ in 0..10 {
+            game.update();
+        }
+        assert_eq!(game.get_production_queue().current(), progress);
+        let brownouts: Vec<GameEvent> = receiver.try_iter()
+            .map(|(_, event)| event)
+            .filter(|event| matches!(event, GameEvent::Brownout { .. }))
+            .collect();
+        assert_eq!(brownouts, vec![GameEvent::Brownout { active: true }]);
+    }
+}
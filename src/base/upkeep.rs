@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Unité des coûts d'entretien : un millième d'Énergie.
+pub const MILLI_ENERGY: u32 = 1000;
+/// Entretien maximal accepté pour chaque poste d'un scénario : 100 Énergie par tick.
+pub const MAX_UPKEEP: u32 = 100 * MILLI_ENERGY;
+
+/// Énergie consommée par la base à chaque tick, en millièmes d'unité :
+/// le support vital, plus une part pour chaque robot en état de marche.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Upkeep {
+    pub life_support: u32,
+    pub per_robot: u32,
+}
+
+impl Upkeep {
+    pub fn cost(&self, active_robots: usize) -> u32 {
+        let robots = u32::try_from(active_robots).unwrap_or(u32::MAX);
+        self.life_support.saturating_add(self.per_robot.saturating_mul(robots))
+    }
+}
+
+impl Default for Upkeep {
+    fn default() -> Self {
+        Self { life_support: 10, per_robot: 1 }
+    }
+}
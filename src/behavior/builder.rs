@@ -0,0 +1,19 @@
+use crate::robot::{Robot, RobotState};
+use super::robot_behavior::{Action, RobotBehavior, WorldView};
+
+/// Rejoint le chantier confié par la base puis y construit l'ouvrage.
+pub struct BuilderBehavior;
+
+impl RobotBehavior for BuilderBehavior {
+    fn tick(&mut self, robot: &Robot, _world: &WorldView) -> Action {
+        match robot.state {
+            RobotState::Building { .. } => Action::Build,
+            RobotState::GoingToSite => match robot.target() {
+                Some((x, y)) if robot.x == x && robot.y == y => Action::Build,
+                Some((x, y)) => Action::MoveToward { x, y },
+                None => Action::Wait,
+            },
+            _ => Action::Wait,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::robot::{Robot, RobotState};
+use super::robot_behavior::{Action, RobotBehavior, WorldView};
+
+/// Va chercher la ressource assignée puis la rapporte à la base ou au dépôt le plus proche.
+pub struct CollectorBehavior;
+
+impl RobotBehavior for CollectorBehavior {
+    fn tick(&mut self, robot: &Robot, world: &WorldView) -> Action {
+        match robot.state {
+            // Soute partiellement remplie sans nouvelle ressource : retour à la base
+            RobotState::Idle if !robot.inventory.is_empty() => unload_at_nearest(robot, world),
+            RobotState::Idle => Action::Wait,
+            RobotState::GoingToResource => match robot.target() {
+                Some((x, y)) if robot.x == x && robot.y == y => Action::Collect,
+                Some((x, y)) => Action::MoveToward { x, y },
+                None => Action::Wait,
+            },
+            RobotState::Mining { .. } => Action::Collect,
+            RobotState::Unloading { .. } => Action::Unload,
+            RobotState::ReturningToBase => unload_at_nearest(robot, world),
+            // Panne ou états propres aux bâtisseurs et aux relais
+            RobotState::Broken | RobotState::GoingToSite | RobotState::Building { .. } | RobotState::Deployed => {
+                Action::Wait
+            }
+        }
+    }
+}
+
+fn unload_at_nearest(robot: &Robot, world: &WorldView) -> Action {
+    let (x, y) = world.nearest_drop_off(robot);
+    if robot.x == x && robot.y == y {
+        Action::Unload
+    } else {
+        Action::MoveToward { x, y }
+    }
+}
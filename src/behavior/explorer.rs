@@ -0,0 +1,52 @@
+use crate::exploration::nearest_frontier;
+use crate::robot::Robot;
+use super::robot_behavior::{Action, RobotBehavior, WorldView};
+
+// Nombre de ressources non signalées au-delà duquel l'explorateur rentre faire son rapport
+const MAX_PENDING_SIGHTINGS: usize = 5;
+// Âge maximal, en ticks, d'une découverte non signalée
+const MAX_REPORT_DELAY: u64 = 300;
+
+/// Ancienne stratégie : un pas aléatoire par tick.
+pub struct RandomWalkExplorer;
+
+impl RobotBehavior for RandomWalkExplorer {
+    fn tick(&mut self, _robot: &Robot, _world: &WorldView) -> Action {
+        Action::Wander
+    }
+}
+
+/// Se dirige vers la frontière la plus proche non revendiquée par un autre explorateur.
+pub struct FrontierExplorer;
+
+impl RobotBehavior for FrontierExplorer {
+    fn tick(&mut self, robot: &Robot, world: &WorldView) -> Action {
+        if needs_to_report(robot, world.tick) {
+            let (x, y) = world.nearest_dock(robot);
+            return Action::MoveToward { x, y };
+        }
+
+        if let Some((target_x, target_y)) = robot.exploration_target {
+            if !world.explored[target_y][target_x] {
+                return Action::Explore { x: target_x, y: target_y };
+            }
+        }
+
+        let claims: Vec<(usize, usize)> = world.robots.iter()
+            .filter(|other| other.id != robot.id)
+            .filter_map(|other| other.exploration_target)
+            .collect();
+
+        match nearest_frontier(world.map, world.explored, (robot.x, robot.y), &claims) {
+            Some((x, y)) => Action::Explore { x, y },
+            None => Action::Wait,
+        }
+    }
+}
+
+/// Vrai si l'explorateur a trop de découvertes en attente, ou une trop ancienne.
+fn needs_to_report(robot: &Robot, tick: u64) -> bool {
+    let pending = robot.knowledge.pending();
+    pending.len() >= MAX_PENDING_SIGHTINGS
+        || pending.first().is_some_and(|sighting| tick - sighting.tick > MAX_REPORT_DELAY)
+}
@@ -0,0 +1,35 @@
+use crate::robot::{Robot, RobotState};
+use super::robot_behavior::{Action, RobotBehavior, WorldView};
+
+// Stock minimal d'un dépôt pour justifier un voyage
+const MIN_HAUL: u32 = 5;
+
+/// Vide le dépôt le mieux rempli et rapporte son stock à la base.
+pub struct HaulerBehavior;
+
+impl RobotBehavior for HaulerBehavior {
+    fn tick(&mut self, robot: &Robot, world: &WorldView) -> Action {
+        match robot.state {
+            RobotState::Unloading { .. } => Action::Unload,
+            RobotState::Idle if robot.inventory.is_empty() => {
+                let fullest = world.depots.iter()
+                    .filter(|depot| depot.total() >= MIN_HAUL)
+                    .max_by_key(|depot| (depot.total(), std::cmp::Reverse((depot.x, depot.y))));
+                match fullest {
+                    Some(depot) if robot.x == depot.x && robot.y == depot.y => Action::Load,
+                    Some(depot) => Action::MoveToward { x: depot.x, y: depot.y },
+                    None => Action::Wait,
+                }
+            }
+            RobotState::Idle | RobotState::ReturningToBase => {
+                let (dock_x, dock_y) = world.nearest_dock(robot);
+                if robot.x == dock_x && robot.y == dock_y {
+                    Action::Unload
+                } else {
+                    Action::MoveToward { x: dock_x, y: dock_y }
+                }
+            }
+            _ => Action::Wait,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod builder;
+pub mod collector;
+pub mod explorer;
+pub mod hauler;
+pub mod registry;
+pub mod relay;
+pub mod rescue;
+pub mod robot_behavior;
+#[cfg(test)]
+mod tests;
+
+pub use robot_behavior::{Action, RobotBehavior, WorldView};
+pub use builder::BuilderBehavior;
+pub use collector::CollectorBehavior;
+pub use explorer::{FrontierExplorer, RandomWalkExplorer};
+pub use hauler::HaulerBehavior;
+pub use registry::BehaviorRegistry;
+pub use relay::RelayBehavior;
+pub use rescue::RescueBehavior;
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use crate::robot::RobotType;
+use super::robot_behavior::RobotBehavior;
+use super::builder::BuilderBehavior;
+use super::collector::CollectorBehavior;
+use super::explorer::FrontierExplorer;
+use super::hauler::HaulerBehavior;
+use super::relay::RelayBehavior;
+use super::rescue::RescueBehavior;
+
+/// Associe chaque type de robot au comportement qui le pilote.
+pub struct BehaviorRegistry {
+    behaviors: HashMap<RobotType, Box<dyn RobotBehavior>>,
+}
+
+impl BehaviorRegistry {
+    pub fn new() -> Self {
+        Self { behaviors: HashMap::new() }
+    }
+
+    /// Remplace le comportement éventuellement déjà associé à ce type.
+    pub fn register(&mut self, robot_type: RobotType, behavior: Box<dyn RobotBehavior>) {
+        self.behaviors.insert(robot_type, behavior);
+    }
+
+    pub fn get_mut(&mut self, robot_type: RobotType) -> Option<&mut (dyn RobotBehavior + 'static)> {
+        self.behaviors.get_mut(&robot_type).map(|behavior| behavior.as_mut())
+    }
+}
+
+impl Default for BehaviorRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(RobotType::Explorer, Box::new(FrontierExplorer));
+        registry.register(RobotType::Miner, Box::new(CollectorBehavior));
+        registry.register(RobotType::EnergyCollector, Box::new(CollectorBehavior));
+        registry.register(RobotType::Scientist, Box::new(CollectorBehavior));
+        registry.register(RobotType::Rescue, Box::new(RescueBehavior));
+        registry.register(RobotType::Builder, Box::new(BuilderBehavior));
+        registry.register(RobotType::Hauler, Box::new(HaulerBehavior));
+        registry.register(RobotType::Relay, Box::new(RelayBehavior));
+        registry
+    }
+}
@@ -0,0 +1,37 @@
+use crate::exploration::nearest_frontier;
+use crate::robot::Robot;
+use super::robot_behavior::{Action, RobotBehavior, WorldView};
+
+/// Part vers la frontière la plus proche et se déploie à la limite du réseau radio,
+/// pour que les découvertes faites au-delà parviennent à la base.
+pub struct RelayBehavior;
+
+impl RobotBehavior for RelayBehavior {
+    fn tick(&mut self, robot: &Robot, world: &WorldView) -> Action {
+        if robot.is_deployed() {
+            return Action::Wait;
+        }
+
+        match nearest_frontier(world.map, world.explored, (robot.x, robot.y), &[]) {
+            Some((x, y)) if !is_covered(world.comms, x, y) && is_coverage_edge(world.comms, robot.x, robot.y) => {
+                Action::Deploy
+            }
+            Some((x, y)) => Action::MoveToward { x, y },
+            None => Action::Wait,
+        }
+    }
+}
+
+fn is_covered(comms: &[Vec<bool>], x: usize, y: usize) -> bool {
+    comms.get(y).and_then(|row| row.get(x)).copied().unwrap_or(false)
+}
+
+/// Case couverte dont au moins une voisine (diagonales comprises) ne l'est pas.
+fn is_coverage_edge(comms: &[Vec<bool>], x: usize, y: usize) -> bool {
+    if !is_covered(comms, x, y) {
+        return false;
+    }
+    (-1isize..=1).flat_map(|dy| (-1isize..=1).map(move |dx| (dx, dy)))
+        .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+        .any(|(nx, ny)| ny < comms.len() && nx < comms[ny].len() && !comms[ny][nx])
+}
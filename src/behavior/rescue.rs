@@ -0,0 +1,42 @@
+use crate::map::tile::Tile;
+use crate::robot::Robot;
+use super::robot_behavior::{Action, RobotBehavior, WorldView};
+
+/// Va chercher le robot en panne le plus proche que personne d'autre n'a pris en charge
+/// et le remorque jusqu'à la base, où il sera réparé.
+pub struct RescueBehavior;
+
+impl RobotBehavior for RescueBehavior {
+    fn tick(&mut self, robot: &Robot, world: &WorldView) -> Action {
+        // Les réparations se font n'importe où sur la base : on vise le quai le plus proche
+        let (base_x, base_y) = world.nearest_dock(robot);
+        let at_base = world.map[robot.y][robot.x] == Tile::Base;
+
+        if robot.towing.is_some() {
+            return if at_base { Action::Wait } else { Action::MoveToward { x: base_x, y: base_y } };
+        }
+
+        if let Some(target) = robot.rescue_target {
+            if world.robots.get(target).is_some_and(|other| other.is_broken()) {
+                return Action::Rescue { target };
+            }
+        }
+
+        let claimed: Vec<_> = world.robots.iter()
+            .filter(|other| other.id != robot.id)
+            .flat_map(|other| other.rescue_target.into_iter().chain(other.towing))
+            .collect();
+
+        // Les robots en panne déjà sur la base attendent simplement leur réparation
+        let nearest = world.robots.iter()
+            .filter(|other| other.is_broken() && !claimed.contains(&other.id))
+            .filter(|other| world.map[other.y][other.x] != Tile::Base)
+            .min_by_key(|other| (other.x.abs_diff(robot.x) + other.y.abs_diff(robot.y), other.id));
+
+        match nearest {
+            Some(other) => Action::Rescue { target: other.id },
+            None if !at_base => Action::MoveToward { x: base_x, y: base_y },
+            None => Action::Wait,
+        }
+    }
+}
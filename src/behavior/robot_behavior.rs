@@ -0,0 +1,87 @@
+use crate::base::Depot;
+use crate::game::DiscoveredResource;
+use crate::map::tile::Tile;
+use crate::robot::{Robot, RobotId, RobotRegistry, RobotState};
+
+/// Décision prise par un comportement pour un robot pendant un tick.
+/// `GameState` se charge de l'appliquer au monde.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Wait,
+    /// Pas aléatoire vers une case voisine, tiré par le moteur
+    Wander,
+    /// Revendique une case frontière et avance d'un pas vers elle
+    Explore { x: usize, y: usize },
+    MoveToward { x: usize, y: usize },
+    /// Extrait la ressource sur laquelle se trouve le robot (sur plusieurs ticks)
+    Collect,
+    /// Décharge l'inventaire dans la base (sur plusieurs ticks)
+    Unload,
+    /// Avance vers un robot en panne et l'attelle une fois arrivé sur sa case
+    Rescue { target: RobotId },
+    /// Construit (sur plusieurs ticks) l'ouvrage du chantier confié au robot
+    Build,
+    /// Charge le stock du dépôt sur lequel se trouve le robot
+    Load,
+    /// Installe le robot sur place comme relais radio
+    Deploy,
+}
+
+/// Vue en lecture seule du monde passée aux comportements.
+pub struct WorldView<'a> {
+    pub map: &'a [Vec<Tile>],
+    pub explored: &'a [Vec<bool>], // Cases connues du robot dont c'est le tour
+    pub robots: &'a RobotRegistry,
+    pub discovered_resources: &'a [DiscoveredResource],
+    pub base: (usize, usize),
+    pub depots: &'a [Depot],
+    pub docks: &'a [(usize, usize)],
+    pub comms: &'a [Vec<bool>], // Cases couvertes par le réseau radio relié à la base
+    pub regions: &'a [Vec<Option<u32>>], // Zones praticables connexes, voir `label_regions`
+    pub tick: u64,
+}
+
+impl WorldView<'_> {
+    /// Vrai si un autre robot que `robot_id` décharge sur ce quai.
+    pub fn is_dock_occupied(&self, dock: (usize, usize), robot_id: RobotId) -> bool {
+        self.robots.iter().any(|other| {
+            other.id != robot_id
+                && (other.x, other.y) == dock
+                && matches!(other.state, RobotState::Unloading { .. })
+        })
+    }
+
+    /// Vrai si la case est dans la même zone praticable que le robot. Sans zones
+    /// connues pour le robot, toute case est supposée accessible.
+    pub fn is_reachable(&self, robot: &Robot, (x, y): (usize, usize)) -> bool {
+        let region = |x: usize, y: usize| self.regions.get(y).and_then(|row| row.get(x)).copied().flatten();
+        match region(robot.x, robot.y) {
+            Some(own) => region(x, y) == Some(own),
+            None => true,
+        }
+    }
+
+    /// Quai de la base le plus proche (distance de Manhattan). Les quais inaccessibles,
+    /// puis occupés, ne sont choisis que si tous le sont ; sans quai, on vise le coin de la base.
+    pub fn nearest_dock(&self, robot: &Robot) -> (usize, usize) {
+        let distance = |(px, py): (usize, usize)| px.abs_diff(robot.x) + py.abs_diff(robot.y);
+        self.docks.iter()
+            .copied()
+            .min_by_key(|&dock| (!self.is_reachable(robot, dock), self.is_dock_occupied(dock, robot.id), distance(dock)))
+            .unwrap_or(self.base)
+    }
+
+    /// Quai libre ou dépôt le plus proche, le quai à égalité. Un point de dépose
+    /// inaccessible n'est visé qu'à défaut d'autre, pour ne pas garder la cargaison à bord.
+    pub fn nearest_drop_off(&self, robot: &Robot) -> (usize, usize) {
+        let distance = |(px, py): (usize, usize)| px.abs_diff(robot.x) + py.abs_diff(robot.y);
+        let key = |point| (!self.is_reachable(robot, point), distance(point));
+        self.depots.iter()
+            .map(|depot| (depot.x, depot.y))
+            .fold(self.nearest_dock(robot), |best, depot| if key(depot) < key(best) { depot } else { best })
+    }
+}
+
+pub trait RobotBehavior {
+    fn tick(&mut self, robot: &Robot, world: &WorldView) -> Action;
+}
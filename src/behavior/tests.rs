@@ -0,0 +1,263 @@
+#[cfg(test)]
+mod behavior_tests {
+    use super::super::{
+        Action, BehaviorRegistry, CollectorBehavior, FrontierExplorer, HaulerBehavior, RelayBehavior, RescueBehavior, RobotBehavior,
+        WorldView,
+    };
+    use crate::base::Depot;
+    use crate::knowledge::KnowledgeMap;
+    use crate::map::label_regions;
+    use crate::map::tile::Tile;
+    use crate::robot::{Robot, RobotRegistry, RobotType};
+
+    struct AlwaysWait;
+
+    impl RobotBehavior for AlwaysWait {
+        fn tick(&mut self, _robot: &Robot, _world: &WorldView) -> Action {
+            Action::Wait
+        }
+    }
+
+    fn world<'a>(map: &'a [Vec<Tile>], explored: &'a [Vec<bool>], robots: &'a RobotRegistry) -> WorldView<'a> {
+        WorldView {
+            map,
+            explored,
+            robots,
+            discovered_resources: &[],
+            base: (0, 0),
+            depots: &[],
+            docks: &[],
+            comms: &[],
+            regions: &[],
+            tick: 0,
+        }
+    }
+
+    #[test]
+    fn test_frontier_explorer_targets_nearest_frontier() {
+        let map = vec![vec![Tile::Empty; 6]];
+        let explored = vec![vec![true, true, true, false, false, false]];
+        let mut robots = RobotRegistry::new();
+        let id = robots.spawn(1, 0, RobotType::Explorer);
+
+        let action = FrontierExplorer.tick(robots.get(id).unwrap(), &world(&map, &explored, &robots));
+
+        assert_eq!(action, Action::Explore { x: 3, y: 0 });
+    }
+
+    #[test]
+    fn test_frontier_explorer_keeps_its_claim() {
+        let map = vec![vec![Tile::Empty; 6]];
+        let explored = vec![vec![false, true, true, false, false, false]];
+        let mut robot = Robot::new(1, 0, RobotType::Explorer);
+        robot.exploration_target = Some((3, 0));
+        let mut robots = RobotRegistry::new();
+        let id = robots.insert(robot);
+
+        let action = FrontierExplorer.tick(robots.get(id).unwrap(), &world(&map, &explored, &robots));
+
+        assert_eq!(action, Action::Explore { x: 3, y: 0 });
+    }
+
+    #[test]
+    fn test_frontier_explorer_returns_to_report() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Mineral, Tile::Empty, Tile::Empty, Tile::Empty]];
+        let explored = vec![vec![true, true, true, false, false, false]];
+        let mut robot = Robot::new(2, 0, RobotType::Explorer);
+        robot.knowledge = KnowledgeMap::new(6, 1);
+        robot.knowledge.observe(2, 0, Tile::Mineral, 0);
+        let mut robots = RobotRegistry::new();
+        let id = robots.insert(robot);
+
+        // Découverte récente : l'exploration continue
+        let view = WorldView { tick: 100, ..world(&map, &explored, &robots) };
+        assert_eq!(FrontierExplorer.tick(robots.get(id).unwrap(), &view), Action::Explore { x: 3, y: 0 });
+
+        // Découverte trop ancienne : retour à la base pour la signaler
+        let view = WorldView { tick: 400, ..world(&map, &explored, &robots) };
+        assert_eq!(FrontierExplorer.tick(robots.get(id).unwrap(), &view), Action::MoveToward { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_collector_behavior() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Mineral]];
+        let explored = vec![vec![true; 3]];
+
+        let idle = Robot::new(0, 0, RobotType::Miner);
+        let mut going = Robot::new(0, 0, RobotType::Miner);
+        going.set_target(2, 0);
+        let mut arrived = Robot::new(2, 0, RobotType::Miner);
+        arrived.set_target(2, 0);
+        let mut returning = Robot::new(0, 0, RobotType::Miner);
+        returning.set_returning_to_base(0, 0);
+
+        let mut mining = Robot::new(2, 0, RobotType::Miner);
+        mining.set_target(2, 0);
+        mining.advance_mining();
+        let mut unloading = Robot::new(0, 0, RobotType::Miner);
+        unloading.set_returning_to_base(0, 0);
+        unloading.advance_unloading();
+
+        let robots: RobotRegistry = vec![idle, going, arrived, returning, mining, unloading].into_iter().collect();
+        let view = world(&map, &explored, &robots);
+        let actions: Vec<Action> = robots.iter().map(|robot| CollectorBehavior.tick(robot, &view)).collect();
+
+        assert_eq!(actions, vec![
+            Action::Wait,
+            Action::MoveToward { x: 2, y: 0 },
+            Action::Collect,
+            Action::Unload,
+            Action::Collect,
+            Action::Unload,
+        ]);
+    }
+
+    #[test]
+    fn test_collector_unloads_at_nearest_depot() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Empty, Tile::Empty, Tile::Empty]];
+        let explored = vec![vec![true; 5]];
+        let robots = RobotRegistry::new();
+        let depots = vec![Depot::new(4, 0)];
+        let view = WorldView { depots: &depots, ..world(&map, &explored, &robots) };
+
+        let mut near_depot = Robot::new(3, 0, RobotType::Miner);
+        near_depot.collect(Tile::Mineral);
+        near_depot.set_returning_to_base(0, 0);
+        let mut near_base = Robot::new(1, 0, RobotType::Miner);
+        near_base.collect(Tile::Mineral);
+        near_base.set_returning_to_base(0, 0);
+
+        assert_eq!(CollectorBehavior.tick(&near_depot, &view), Action::MoveToward { x: 4, y: 0 });
+        assert_eq!(CollectorBehavior.tick(&near_base, &view), Action::MoveToward { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_collector_skips_unreachable_depot() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Empty, Tile::Obstacle, Tile::Depot]];
+        let explored = vec![vec![true; 5]];
+        let robots = RobotRegistry::new();
+        let depots = vec![Depot::new(4, 0)];
+        let regions = label_regions(&map);
+        let view = WorldView { depots: &depots, regions: &regions, ..world(&map, &explored, &robots) };
+
+        let mut returning = Robot::new(2, 0, RobotType::Miner);
+        returning.collect(Tile::Mineral);
+        returning.set_returning_to_base(0, 0);
+
+        assert!(!view.is_reachable(&returning, (4, 0)));
+        assert_eq!(CollectorBehavior.tick(&returning, &view), Action::MoveToward { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_nearest_free_dock() {
+        let map = vec![vec![Tile::Base, Tile::Base, Tile::Base, Tile::Empty]];
+        let explored = vec![vec![true; 4]];
+        let mut robots = RobotRegistry::new();
+        let mut unloading = Robot::new(2, 0, RobotType::Miner);
+        unloading.collect(Tile::Mineral);
+        unloading.set_returning_to_base(2, 0);
+        unloading.advance_unloading();
+        robots.insert(unloading);
+        let mut returning = Robot::new(3, 0, RobotType::Miner);
+        returning.collect(Tile::Mineral);
+        returning.set_returning_to_base(0, 0);
+        let returning = robots.insert(returning);
+
+        let docks = vec![(0, 0), (2, 0)];
+        let view = WorldView { docks: &docks, ..world(&map, &explored, &robots) };
+        let returning = robots.get(returning).unwrap();
+
+        // Le quai le plus proche est occupé : on vise l'autre
+        assert_eq!(view.nearest_dock(returning), (0, 0));
+        assert_eq!(CollectorBehavior.tick(returning, &view), Action::MoveToward { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_hauler_behavior() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Empty, Tile::Empty]];
+        let explored = vec![vec![true; 4]];
+        let robots = RobotRegistry::new();
+        let mut full = Depot::new(3, 0);
+        for _ in 0..5 {
+            full.deposit(Tile::Energy);
+        }
+        let mut depots = vec![Depot::new(1, 0), full];
+        depots[0].deposit(Tile::Science);
+        let view = WorldView { depots: &depots, ..world(&map, &explored, &robots) };
+
+        // Le dépôt voisin n'a pas assez de stock pour justifier le voyage
+        let empty = Robot::new(0, 0, RobotType::Hauler);
+        assert_eq!(HaulerBehavior.tick(&empty, &view), Action::MoveToward { x: 3, y: 0 });
+        let at_depot = Robot::new(3, 0, RobotType::Hauler);
+        assert_eq!(HaulerBehavior.tick(&at_depot, &view), Action::Load);
+
+        let mut loaded = Robot::new(3, 0, RobotType::Hauler);
+        loaded.inventory.push(Tile::Energy);
+        loaded.set_returning_to_base(0, 0);
+        assert_eq!(HaulerBehavior.tick(&loaded, &view), Action::MoveToward { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_relay_deploys_at_network_edge() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Empty, Tile::Empty, Tile::Empty, Tile::Empty]];
+        let explored = vec![vec![true, true, true, true, true, false]];
+        let comms = vec![vec![true, true, true, false, false, false]];
+        let robots = RobotRegistry::new();
+        let view = WorldView { comms: &comms, ..world(&map, &explored, &robots) };
+
+        let leaving = Robot::new(1, 0, RobotType::Relay);
+        assert_eq!(RelayBehavior.tick(&leaving, &view), Action::MoveToward { x: 5, y: 0 });
+        let at_edge = Robot::new(2, 0, RobotType::Relay);
+        assert_eq!(RelayBehavior.tick(&at_edge, &view), Action::Deploy);
+
+        let mut deployed = Robot::new(2, 0, RobotType::Relay);
+        deployed.deploy();
+        assert_eq!(RelayBehavior.tick(&deployed, &view), Action::Wait);
+    }
+
+    #[test]
+    fn test_rescue_behavior() {
+        let map = vec![vec![Tile::Base, Tile::Empty, Tile::Empty, Tile::Empty]];
+        let explored = vec![vec![true; 4]];
+
+        let mut robots = RobotRegistry::new();
+        let rescuer = robots.insert(Robot::new(0, 0, RobotType::Rescue));
+        let near = robots.insert(Robot::new(2, 0, RobotType::Miner));
+        let far = robots.insert(Robot::new(3, 0, RobotType::Explorer));
+        robots.get_mut(near).unwrap().break_down();
+        robots.get_mut(far).unwrap().break_down();
+
+        let view = world(&map, &explored, &robots);
+        let action = RescueBehavior.tick(robots.get(rescuer).unwrap(), &view);
+        assert_eq!(action, Action::Rescue { target: near });
+
+        // Le robot le plus proche est déjà pris en charge par un autre dépanneur
+        let mut other = Robot::new(0, 0, RobotType::Rescue);
+        other.rescue_target = Some(near);
+        robots.insert(other);
+        let view = world(&map, &explored, &robots);
+        let action = RescueBehavior.tick(robots.get(rescuer).unwrap(), &view);
+        assert_eq!(action, Action::Rescue { target: far });
+
+        let mut towing = Robot::new(2, 0, RobotType::Rescue);
+        towing.towing = Some(near);
+        assert_eq!(RescueBehavior.tick(&towing, &view), Action::MoveToward { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_registry_custom_behavior() {
+        let map = vec![vec![Tile::Empty; 3]];
+        let explored = vec![vec![false; 3]];
+        let mut robots = RobotRegistry::new();
+        let id = robots.spawn(0, 0, RobotType::Explorer);
+        let view = world(&map, &explored, &robots);
+        let robot = robots.get(id).unwrap();
+
+        let mut registry = BehaviorRegistry::default();
+        assert_ne!(registry.get_mut(RobotType::Explorer).unwrap().tick(robot, &view), Action::Wait);
+
+        registry.register(RobotType::Explorer, Box::new(AlwaysWait));
+        assert_eq!(registry.get_mut(RobotType::Explorer).unwrap().tick(robot, &view), Action::Wait);
+        assert!(BehaviorRegistry::new().get_mut(RobotType::Explorer).is_none());
+    }
+}
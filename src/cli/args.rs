@@ -0,0 +1,240 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::config::{ConfigError, Scenario};
+use crate::map::MapGenerator;
+use crate::robot::RobotType;
+
+// Durée d'une simulation sans interface quand `--ticks` n'est pas précisé
+pub const DEFAULT_HEADLESS_TICKS: u64 = 1000;
+
+pub const USAGE: &str = "\
+Utilisation : astro_swarm [SOUS-COMMANDE] [OPTIONS]
+
+Sous-commandes :
+  run                 partie interactive dans le terminal (par défaut)
+  headless            simulation sans interface, suivie d'un bilan
+  gen-map             affiche la carte générée puis quitte
+  dump-config         affiche la configuration effective au format TOML
+  replay <FICHIER>    rejoue une partie enregistrée
+  help                affiche cette aide
+
+Options de monde (run, headless, gen-map, dump-config) :
+  --config <FICHIER>      scénario TOML ; les autres options l'emportent sur ses valeurs
+  --width <N>             largeur de la carte (80 par défaut)
+  --height <N>            hauteur de la carte (80 par défaut)
+  --seed <N>              graine de génération (1337 par défaut)
+  --generator <NOM>       perlin, caves ou open (perlin par défaut), avec ses réglages de terrain
+  --robots <TYPE=N,...>   flotte de départ, par exemple explorer=4,miner=3
+                          types : explorer, miner, collector, scientist, rescue, builder, hauler, relay
+
+Options de run et headless :
+  --load <FICHIER>        reprend une partie sauvegardée, à la place des options de monde
+  --metrics <FICHIER>     exporte en fin de partie les relevés de chaque tick au format CSV
+
+Options de run :
+  --record <FICHIER>      enregistre la partie pour la rejouer avec replay (sans --load)
+
+Options de headless :
+  --ticks <N>             nombre de ticks simulés (1000 par défaut)
+  --json                  bilan au format JSON";
+
+/// Ce que l'utilisateur a demandé en ligne de commande.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `load` : sauvegarde à reprendre, le scénario restant alors celui par défaut ;
+    /// `record` : fichier où enregistrer la partie ; `metrics` : fichier CSV des relevés
+    Run { scenario: Scenario, load: Option<String>, record: Option<String>, metrics: Option<String> },
+    Headless { scenario: Scenario, ticks: u64, json: bool, load: Option<String>, metrics: Option<String> },
+    GenMap(Scenario),
+    DumpConfig(Scenario),
+    Replay { path: String },
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    UnknownCommand(String),
+    UnknownOption(String),
+    /// Option valable, mais pas pour cette sous-commande
+    OptionNotAllowed { option: String, command: &'static str },
+    MissingValue(&'static str),
+    InvalidValue { option: &'static str, value: String, expected: String },
+    UnexpectedArgument(String),
+    MissingReplayFile,
+    /// Option de monde ou d'enregistrement donnée avec `--load`
+    ConflictsWithLoad(String),
+    Config(ConfigError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::UnknownCommand(command) => write!(f, "sous-commande inconnue : {}", command),
+            CliError::UnknownOption(option) => write!(f, "option inconnue : {}", option),
+            CliError::OptionNotAllowed { option, command } => {
+                write!(f, "l'option {} ne s'applique pas à la sous-commande {}", option, command)
+            }
+            CliError::MissingValue(option) => write!(f, "l'option {} attend une valeur", option),
+            CliError::InvalidValue { option, value, expected } => {
+                write!(f, "valeur invalide pour {} : « {} » ({})", option, value, expected)
+            }
+            CliError::UnexpectedArgument(argument) => write!(f, "argument inattendu : {}", argument),
+            CliError::MissingReplayFile => write!(f, "replay attend le chemin d'un fichier enregistré"),
+            CliError::ConflictsWithLoad(option) => {
+                write!(f, "l'option {} est incompatible avec --load, qui reprend le monde de la sauvegarde", option)
+            }
+            CliError::Config(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Analyse les arguments (sans le nom du programme). Sans sous-commande, la partie
+/// est interactive ; `--headless` reste accepté comme raccourci de `headless`.
+/// Le scénario de `--config` est lu en premier, quelle que soit sa position.
+pub fn parse_args(args: &[String]) -> Result<Command, CliError> {
+    let (mut command, options) = match args.first().map(String::as_str) {
+        Some("run") => ("run", &args[1..]),
+        Some("headless") => ("headless", &args[1..]),
+        Some("gen-map") => ("gen-map", &args[1..]),
+        Some("dump-config") => ("dump-config", &args[1..]),
+        Some("replay") => ("replay", &args[1..]),
+        Some("help") => return Ok(Command::Help),
+        Some(first) if !first.starts_with('-') => return Err(CliError::UnknownCommand(first.to_string())),
+        _ => ("run", args),
+    };
+
+    let mut scenario = match options.iter().position(|arg| arg == "--config") {
+        Some(index) => {
+            let path = options.get(index + 1).ok_or(CliError::MissingValue("--config"))?;
+            Scenario::load(path).map_err(CliError::Config)?
+        }
+        None => Scenario::default(),
+    };
+    let world = &mut scenario.world;
+    let mut ticks = DEFAULT_HEADLESS_TICKS;
+    let mut json = false;
+    let mut load = None;
+    let mut record = None;
+    let mut metrics = None;
+    let mut world_options = Vec::new();
+    let mut headless_options = Vec::new();
+    let mut play_options = Vec::new();
+    let mut record_options = Vec::new();
+    let mut positional = Vec::new();
+
+    let mut iter = options.iter();
+    while let Some(arg) = iter.next() {
+        // Portée de l'option, vérifiée une fois la sous-commande connue
+        match arg.as_str() {
+            "--config" | "--width" | "--height" | "--seed" | "--generator" | "--robots" => {
+                world_options.push(arg.clone())
+            }
+            "--ticks" | "--json" => headless_options.push(arg.clone()),
+            "--load" | "--metrics" => play_options.push(arg.clone()),
+            "--record" => record_options.push(arg.clone()),
+            _ => {}
+        }
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--headless" if command == "run" => command = "headless",
+            "--config" => {
+                iter.next();
+            }
+            "--width" => world.width = parse_number("--width", iter.next())?,
+            "--height" => world.height = parse_number("--height", iter.next())?,
+            "--seed" => world.seed = parse_number("--seed", iter.next())?,
+            "--generator" => world.set_generator(parse_generator(iter.next())?),
+            "--robots" => world.robots = parse_robots(iter.next())?,
+            "--ticks" => ticks = parse_number("--ticks", iter.next())?,
+            "--json" => json = true,
+            "--load" => load = Some(iter.next().ok_or(CliError::MissingValue("--load"))?.clone()),
+            "--record" => record = Some(iter.next().ok_or(CliError::MissingValue("--record"))?.clone()),
+            "--metrics" => metrics = Some(iter.next().ok_or(CliError::MissingValue("--metrics"))?.clone()),
+            option if option.starts_with('-') => return Err(CliError::UnknownOption(option.to_string())),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    if command != "headless" {
+        if let Some(option) = headless_options.into_iter().next() {
+            return Err(CliError::OptionNotAllowed { option, command });
+        }
+    }
+    if command != "run" && command != "headless" {
+        if let Some(option) = play_options.into_iter().next() {
+            return Err(CliError::OptionNotAllowed { option, command });
+        }
+    }
+    if command != "run" {
+        if let Some(option) = record_options.first() {
+            return Err(CliError::OptionNotAllowed { option: option.clone(), command });
+        }
+    }
+    if load.is_some() {
+        if let Some(option) = world_options.iter().chain(&record_options).next() {
+            return Err(CliError::ConflictsWithLoad(option.clone()));
+        }
+    }
+
+    match command {
+        "replay" => {
+            if let Some(option) = world_options.into_iter().next() {
+                return Err(CliError::OptionNotAllowed { option, command });
+            }
+            let mut positional = positional.into_iter();
+            let path = positional.next().ok_or(CliError::MissingReplayFile)?;
+            match positional.next() {
+                Some(extra) => Err(CliError::UnexpectedArgument(extra)),
+                None => Ok(Command::Replay { path }),
+            }
+        }
+        _ if !positional.is_empty() => Err(CliError::UnexpectedArgument(positional.remove(0))),
+        "headless" => Ok(Command::Headless { scenario, ticks, json, load, metrics }),
+        "gen-map" => Ok(Command::GenMap(scenario)),
+        "dump-config" => Ok(Command::DumpConfig(scenario)),
+        _ => Ok(Command::Run { scenario, load, record, metrics }),
+    }
+}
+
+fn parse_number<T: FromStr>(option: &'static str, value: Option<&String>) -> Result<T, CliError> {
+    let value = value.ok_or(CliError::MissingValue(option))?;
+    value.parse().map_err(|_| CliError::InvalidValue {
+        option,
+        value: value.clone(),
+        expected: "entier positif attendu".to_string(),
+    })
+}
+
+fn parse_generator(value: Option<&String>) -> Result<MapGenerator, CliError> {
+    let value = value.ok_or(CliError::MissingValue("--generator"))?;
+    MapGenerator::from_key(value).ok_or_else(|| CliError::InvalidValue {
+        option: "--generator",
+        value: value.clone(),
+        expected: format!("au choix : {}", MapGenerator::ALL.map(|generator| generator.key()).join(", ")),
+    })
+}
+
+/// Lit une flotte de la forme `explorer=4,miner=3` ; les types absents n'ont aucun robot
+/// et un type répété garde sa dernière valeur.
+fn parse_robots(value: Option<&String>) -> Result<Vec<(RobotType, usize)>, CliError> {
+    let value = value.ok_or(CliError::MissingValue("--robots"))?;
+    let invalid = |part: &str, expected: String| CliError::InvalidValue {
+        option: "--robots",
+        value: part.to_string(),
+        expected,
+    };
+
+    let mut robots: Vec<(RobotType, usize)> = Vec::new();
+    for part in value.split(',').filter(|part| !part.is_empty()) {
+        let (key, count) = part.split_once('=')
+            .ok_or_else(|| invalid(part, "forme TYPE=N attendue".to_string()))?;
+        let robot_type = RobotType::from_key(key.trim()).ok_or_else(|| {
+            invalid(part, format!("types connus : {}", RobotType::ALL.map(|robot_type| robot_type.key()).join(", ")))
+        })?;
+        let count = count.trim().parse()
+            .map_err(|_| invalid(part, "nombre de robots entier attendu".to_string()))?;
+        robots.retain(|&(other, _)| other != robot_type);
+        robots.push((robot_type, count));
+    }
+    Ok(robots)
+}
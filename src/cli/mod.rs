@@ -0,0 +1,5 @@
+pub mod args;
+#[cfg(test)]
+mod tests;
+
+pub use args::{CliError, Command, DEFAULT_HEADLESS_TICKS, USAGE, parse_args};
\ No newline at end of file
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod cli_tests {
+    use super::super::{CliError, Command, DEFAULT_HEADLESS_TICKS, parse_args};
+    use crate::config::Scenario;
+    use crate::map::MapGenerator;
+    use crate::robot::RobotType;
+
+    fn parse(line: &str) -> Result<Command, CliError> {
+        let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn test_defaults_to_interactive_run() {
+        assert_eq!(parse(""), Ok(Command::Run { scenario: Scenario::default(), load: None, record: None, metrics: None }));
+        assert_eq!(parse("--help"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_world_options() {
+        let command = parse("headless --width 40 --height 30 --seed 7 --generator caves --robots explorer=2,miner=1,explorer=3 --json");
+        let Ok(Command::Headless { scenario, ticks, json, load: None, metrics: None }) = command else { panic!("{:?}", command) };
+        let world = scenario.world;
+
+        assert_eq!((world.width, world.height, world.seed), (40, 30, 7));
+        assert_eq!(world.generator, MapGenerator::Caves);
+        assert_eq!(world.terrain, MapGenerator::Caves.params());
+        assert_eq!(world.robots, vec![(RobotType::Miner, 1), (RobotType::Explorer, 3)]);
+        assert_eq!(ticks, DEFAULT_HEADLESS_TICKS);
+        assert!(json);
+
+        // Ancienne forme, toujours acceptée
+        assert!(matches!(parse("--headless --ticks 50"), Ok(Command::Headless { ticks: 50, .. })));
+    }
+
+    #[test]
+    fn test_subcommands() {
+        let Ok(Command::GenMap(scenario)) = parse("gen-map --width 20") else { panic!() };
+        assert_eq!(scenario.world.width, 20);
+        assert!(matches!(parse("dump-config"), Ok(Command::DumpConfig(_))));
+        assert_eq!(parse("replay partie.json"), Ok(Command::Replay { path: "partie.json".to_string() }));
+        let Ok(Command::Run { record, .. }) = parse("run --record partie.json --seed 4") else { panic!() };
+        assert_eq!(record.as_deref(), Some("partie.json"));
+        assert_eq!(
+            parse("headless --record partie.json"),
+            Err(CliError::OptionNotAllowed { option: "--record".to_string(), command: "headless" })
+        );
+        assert_eq!(parse("replay"), Err(CliError::MissingReplayFile));
+        assert_eq!(parse("fly"), Err(CliError::UnknownCommand("fly".to_string())));
+    }
+
+    #[test]
+    fn test_load_saved_game() {
+        assert_eq!(
+            parse("run --load partie.json"),
+            Ok(Command::Run { scenario: Scenario::default(), load: Some("partie.json".to_string()), record: None, metrics: None })
+        );
+        let command = parse("headless --load partie.json --ticks 20");
+        assert!(matches!(command, Ok(Command::Headless { ticks: 20, load: Some(_), .. })), "{:?}", command);
+
+        assert_eq!(parse("--load"), Err(CliError::MissingValue("--load")));
+        assert_eq!(parse("--load partie.json --seed 3"), Err(CliError::ConflictsWithLoad("--seed".to_string())));
+        assert_eq!(parse("--record r.json --load p.json"), Err(CliError::ConflictsWithLoad("--record".to_string())));
+        assert_eq!(
+            parse("gen-map --load partie.json"),
+            Err(CliError::OptionNotAllowed { option: "--load".to_string(), command: "gen-map" })
+        );
+    }
+
+    #[test]
+    fn test_metrics_export() {
+        let Ok(Command::Run { metrics, .. }) = parse("run --metrics releves.csv") else { panic!() };
+        assert_eq!(metrics.as_deref(), Some("releves.csv"));
+        let command = parse("headless --load partie.json --metrics releves.csv");
+        assert!(matches!(command, Ok(Command::Headless { load: Some(_), metrics: Some(_), .. })), "{:?}", command);
+        assert_eq!(parse("headless --metrics"), Err(CliError::MissingValue("--metrics")));
+        assert_eq!(
+            parse("gen-map --metrics releves.csv"),
+            Err(CliError::OptionNotAllowed { option: "--metrics".to_string(), command: "gen-map" })
+        );
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert_eq!(parse("--width"), Err(CliError::MissingValue("--width")));
+        assert!(matches!(parse("--seed -3"), Err(CliError::InvalidValue { option: "--seed", .. })));
+        assert!(matches!(parse("--generator maze"), Err(CliError::InvalidValue { option: "--generator", .. })));
+        assert!(matches!(parse("--robots drone=2"), Err(CliError::InvalidValue { option: "--robots", .. })));
+        assert!(matches!(parse("--robots miner"), Err(CliError::InvalidValue { option: "--robots", .. })));
+        assert_eq!(parse("--speed 2"), Err(CliError::UnknownOption("--speed".to_string())));
+        assert_eq!(
+            parse("gen-map --json"),
+            Err(CliError::OptionNotAllowed { option: "--json".to_string(), command: "gen-map" })
+        );
+        assert_eq!(
+            parse("replay partie.json --seed 3"),
+            Err(CliError::OptionNotAllowed { option: "--seed".to_string(), command: "replay" })
+        );
+        assert_eq!(parse("run carte"), Err(CliError::UnexpectedArgument("carte".to_string())));
+    }
+
+    #[test]
+    fn test_config_file_then_flags() {
+        let path = std::env::temp_dir().join(format!("astro_swarm_cli_{}.toml", std::process::id()));
+        std::fs::write(&path, "[world]\nwidth = 50\nseed = 5\n").unwrap();
+        let path = path.to_str().unwrap();
+
+        // Les options l'emportent sur le fichier, où qu'elles soient placées
+        let Ok(Command::Run { scenario, .. }) = parse(&format!("run --seed 9 --config {}", path)) else { panic!() };
+        assert_eq!((scenario.world.width, scenario.world.seed), (50, 9));
+
+        assert!(matches!(parse("run --config introuvable.toml"), Err(CliError::Config(_))));
+        std::fs::remove_file(path).unwrap();
+    }
+}
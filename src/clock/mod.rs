@@ -0,0 +1,5 @@
+pub mod sim_clock;
+#[cfg(test)]
+mod tests;
+
+pub use sim_clock::{SimClock, DEFAULT_TICK_RATE, MAX_TICK_RATE, MIN_TICK_RATE, SPEEDS};
\ No newline at end of file
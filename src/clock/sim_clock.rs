@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+// Cadence par défaut de la simulation, en ticks par seconde à vitesse 1x
+pub const DEFAULT_TICK_RATE: f64 = 10.0;
+// Cadences acceptées dans un scénario
+pub const MIN_TICK_RATE: f64 = 0.1;
+pub const MAX_TICK_RATE: f64 = 1000.0;
+// Multiplicateurs de vitesse proposés, du plus lent au plus rapide
+pub const SPEEDS: [f64; 9] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0];
+// Nombre maximal de ticks rattrapés en un appel, pour ne pas geler l'affichage
+const MAX_STEPS_PER_ADVANCE: u32 = 200;
+
+/// Horloge à pas fixe : accumule le temps réel écoulé et le convertit en ticks
+/// de simulation, indépendamment de la cadence d'affichage.
+#[derive(Debug, Clone)]
+pub struct SimClock {
+    tick_rate: f64,
+    speed_index: usize,
+    accumulator: Duration,
+    paused: bool,
+    pending_steps: u32, // Ticks demandés pas à pas pendant la pause
+}
+
+impl SimClock {
+    pub fn new(tick_rate: f64) -> Self {
+        Self {
+            tick_rate,
+            speed_index: SPEEDS.iter().position(|&speed| speed == 1.0).unwrap(),
+            accumulator: Duration::ZERO,
+            paused: false,
+            pending_steps: 0,
+        }
+    }
+
+    /// Ajoute le temps écoulé et renvoie le nombre de ticks à simuler maintenant.
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        if self.paused {
+            return std::mem::take(&mut self.pending_steps);
+        }
+
+        self.accumulator += elapsed;
+        let interval = self.tick_interval();
+        let mut steps = 0;
+        while self.accumulator >= interval && steps < MAX_STEPS_PER_ADVANCE {
+            self.accumulator -= interval;
+            steps += 1;
+        }
+        // Trop de retard : on abandonne le reste plutôt que de le rattraper
+        if steps == MAX_STEPS_PER_ADVANCE {
+            self.accumulator = Duration::ZERO;
+        }
+        steps
+    }
+
+    /// Durée réelle d'un tick à la vitesse courante.
+    pub fn tick_interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / (self.tick_rate * self.speed()))
+    }
+
+    pub fn speed(&self) -> f64 {
+        SPEEDS[self.speed_index]
+    }
+
+    pub fn faster(&mut self) {
+        self.speed_index = (self.speed_index + 1).min(SPEEDS.len() - 1);
+    }
+
+    pub fn slower(&mut self) {
+        self.speed_index = self.speed_index.saturating_sub(1);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        self.accumulator = Duration::ZERO;
+        self.pending_steps = 0;
+    }
+
+    /// Demande un unique tick ; sans effet hors pause.
+    pub fn step(&mut self) {
+        if self.paused {
+            self.pending_steps += 1;
+        }
+    }
+
+    /// Vitesse affichée dans l'interface, par exemple « x0.25 » ou « Pause ».
+    pub fn label(&self) -> String {
+        if self.paused {
+            "Pause".to_string()
+        } else {
+            format!("x{}", self.speed())
+        }
+    }
+}
+
+impl Default for SimClock {
+    fn default() -> Self {
+        Self::new(DEFAULT_TICK_RATE)
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod clock_tests {
+    use std::time::Duration;
+    use super::super::{SimClock, SPEEDS};
+
+    #[test]
+    fn test_accumulates_fixed_steps() {
+        let mut clock = SimClock::new(10.0);
+
+        assert_eq!(clock.advance(Duration::from_millis(250)), 2);
+        // Les 50 ms restantes sont conservées pour le tick suivant
+        assert_eq!(clock.advance(Duration::from_millis(60)), 1);
+
+        clock.faster();
+        assert_eq!(clock.speed(), 2.0);
+        assert_eq!(clock.advance(Duration::from_millis(100)), 2);
+    }
+
+    #[test]
+    fn test_pause_and_single_step() {
+        let mut clock = SimClock::new(10.0);
+        clock.toggle_pause();
+
+        assert_eq!(clock.advance(Duration::from_secs(1)), 0);
+        clock.step();
+        clock.step();
+        assert_eq!(clock.advance(Duration::from_secs(1)), 2);
+        assert_eq!(clock.advance(Duration::ZERO), 0);
+        assert_eq!(clock.label(), "Pause");
+
+        clock.toggle_pause();
+        clock.step();
+        assert_eq!(clock.advance(Duration::ZERO), 0);
+    }
+
+    #[test]
+    fn test_speed_bounds() {
+        let mut clock = SimClock::default();
+        for _ in 0..20 {
+            clock.slower();
+        }
+        assert_eq!(clock.speed(), SPEEDS[0]);
+        assert_eq!(clock.label(), "x0.25");
+        for _ in 0..20 {
+            clock.faster();
+        }
+        assert_eq!(clock.speed(), 64.0);
+    }
+}
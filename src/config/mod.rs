@@ -0,0 +1,10 @@
+pub mod scenario;
+pub mod world;
+#[cfg(test)]
+mod tests;
+
+pub use scenario::{ConfigError, Scenario};
+pub use world::{
+    BaseSettings, ObjectiveSettings, ResourceAmounts, SetupError, WorldConfig, DEFAULT_COMMS_RANGE, MAX_CARGO_CAPACITY,
+    MAX_FLEET_SIZE, MAX_MAP_SIZE, MAX_ROBOT_HP, MAX_SENSOR_RADIUS, MIN_MAP_SIZE,
+};
\ No newline at end of file
@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use serde::{Deserialize, Serialize};
+use crate::clock::{DEFAULT_TICK_RATE, MAX_TICK_RATE, MIN_TICK_RATE};
+use crate::map::MapGenerator;
+use crate::robot::{RobotStats, RobotType};
+use super::world::{ResourceAmounts, SetupError, WorldConfig};
+
+/// Configuration complète d'une expérience : le monde et la cadence de simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scenario {
+    pub world: WorldConfig,
+    pub tick_rate: f64, // Ticks par seconde à vitesse 1x dans l'interface
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self { world: WorldConfig::default(), tick_rate: DEFAULT_TICK_RATE }
+    }
+}
+
+impl Scenario {
+    /// Lit un scénario TOML ; les clés absentes gardent leur valeur par défaut.
+    pub fn from_toml(text: &str) -> Result<Self, ConfigError> {
+        let file: ScenarioFile = toml::from_str(text).map_err(|error| ConfigError::Parse(error.to_string()))?;
+        let mut scenario = Scenario::default();
+        file.apply_to(&mut scenario)?;
+        scenario.validate().map_err(ConfigError::Invalid)?;
+        Ok(scenario)
+    }
+
+    pub fn load(path: &str) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| ConfigError::Io { path: path.to_string(), message: error.to_string() })?;
+        Self::from_toml(&text)
+    }
+
+    pub fn validate(&self) -> Result<(), SetupError> {
+        self.world.validate()?;
+        if !(self.tick_rate.is_finite() && (MIN_TICK_RATE..=MAX_TICK_RATE).contains(&self.tick_rate)) {
+            return Err(SetupError::InvalidParameter {
+                name: "simulation.tick_rate".to_string(),
+                reason: format!("attendu entre {} et {} ticks par seconde", MIN_TICK_RATE, MAX_TICK_RATE),
+            });
+        }
+        Ok(())
+    }
+
+    /// Configuration effective complète, relisible par `from_toml`.
+    pub fn to_toml(&self) -> String {
+        toml::to_string(&ScenarioFile::from_scenario(self)).expect("un scénario se sérialise toujours")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    Io { path: String, message: String },
+    /// TOML mal formé, clé inconnue ou valeur du mauvais type
+    Parse(String),
+    UnknownRobotType { section: &'static str, key: String },
+    Invalid(SetupError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, message } => write!(f, "lecture de {} impossible : {}", path, message),
+            ConfigError::Parse(message) => write!(f, "fichier de configuration invalide : {}", message.trim_end()),
+            ConfigError::UnknownRobotType { section, key } => write!(
+                f,
+                "type de robot inconnu « {} » dans [{}] (types connus : {})",
+                key,
+                section,
+                RobotType::ALL.map(|robot_type| robot_type.key()).join(", ")
+            ),
+            ConfigError::Invalid(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Contenu d'un fichier de scénario : chaque section et chaque clé est facultative.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct ScenarioFile {
+    world: WorldSection,
+    terrain: TerrainSection,
+    robots: BTreeMap<String, usize>,
+    stats: BTreeMap<String, StatsSection>,
+    base: BaseSection,
+    simulation: SimulationSection,
+    objectives: ObjectivesSection,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct WorldSection {
+    width: Option<usize>,
+    height: Option<usize>,
+    seed: Option<u32>,
+    generator: Option<MapGenerator>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct TerrainSection {
+    scale: Option<f64>,
+    obstacle_threshold: Option<f64>,
+    resource_threshold: Option<f64>,
+    energy_percent: Option<u32>,
+    mineral_percent: Option<u32>,
+    science_percent: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct StatsSection {
+    ticks_per_tile: Option<u32>,
+    mining_ticks: Option<u32>,
+    unloading_ticks: Option<u32>,
+    sensor_radius: Option<usize>,
+    cargo_capacity: Option<u32>,
+    max_hp: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct BaseSection {
+    comms_range: Option<usize>,
+    autobuild: Option<bool>,
+    starting_resources: Option<ResourceAmounts>,
+    upkeep_life_support: Option<u32>, // Millièmes d'Énergie par tick
+    upkeep_per_robot: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct SimulationSection {
+    tick_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+struct ObjectivesSection {
+    delivered: Option<ResourceAmounts>,
+    explored_percent: Option<f64>,
+    survive_ticks: Option<u64>,
+    deadline: Option<u64>,
+    keep_energy: Option<bool>,
+}
+
+fn robot_type(section: &'static str, key: &str) -> Result<RobotType, ConfigError> {
+    RobotType::from_key(key).ok_or_else(|| ConfigError::UnknownRobotType { section, key: key.to_string() })
+}
+
+impl ScenarioFile {
+    /// Reporte sur `scenario` les valeurs présentes dans le fichier.
+    fn apply_to(self, scenario: &mut Scenario) -> Result<(), ConfigError> {
+        let world = &mut scenario.world;
+        world.width = self.world.width.unwrap_or(world.width);
+        world.height = self.world.height.unwrap_or(world.height);
+        world.seed = self.world.seed.unwrap_or(world.seed);
+        if let Some(generator) = self.world.generator {
+            world.set_generator(generator);
+        }
+
+        let terrain = &mut world.terrain;
+        terrain.scale = self.terrain.scale.unwrap_or(terrain.scale);
+        terrain.obstacle_threshold = self.terrain.obstacle_threshold.unwrap_or(terrain.obstacle_threshold);
+        terrain.resource_threshold = self.terrain.resource_threshold.unwrap_or(terrain.resource_threshold);
+        terrain.energy_percent = self.terrain.energy_percent.unwrap_or(terrain.energy_percent);
+        terrain.mineral_percent = self.terrain.mineral_percent.unwrap_or(terrain.mineral_percent);
+        terrain.science_percent = self.terrain.science_percent.unwrap_or(terrain.science_percent);
+
+        for (key, count) in self.robots {
+            let robot_type = robot_type("robots", &key)?;
+            match world.robots.iter_mut().find(|(other, _)| *other == robot_type) {
+                Some(entry) => entry.1 = count,
+                None if count > 0 => world.robots.push((robot_type, count)),
+                None => {}
+            }
+        }
+
+        for (key, section) in self.stats {
+            let robot_type = robot_type("stats", &key)?;
+            let base = world.stats_for(robot_type);
+            let stats = RobotStats {
+                ticks_per_tile: section.ticks_per_tile.unwrap_or(base.ticks_per_tile),
+                mining_ticks: section.mining_ticks.unwrap_or(base.mining_ticks),
+                unloading_ticks: section.unloading_ticks.unwrap_or(base.unloading_ticks),
+                sensor_radius: section.sensor_radius.unwrap_or(base.sensor_radius),
+                cargo_capacity: section.cargo_capacity.unwrap_or(base.cargo_capacity),
+                max_hp: section.max_hp.unwrap_or(base.max_hp),
+            };
+            // Seules les caractéristiques qui s'écartent de celles du type sont retenues
+            if stats == robot_type.base_stats() {
+                world.stats.remove(&robot_type);
+            } else {
+                world.stats.insert(robot_type, stats);
+            }
+        }
+
+        let base = &mut world.base;
+        base.comms_range = self.base.comms_range.unwrap_or(base.comms_range);
+        base.autobuild = self.base.autobuild.unwrap_or(base.autobuild);
+        base.starting_resources = self.base.starting_resources.unwrap_or(base.starting_resources);
+        base.upkeep.life_support = self.base.upkeep_life_support.unwrap_or(base.upkeep.life_support);
+        base.upkeep.per_robot = self.base.upkeep_per_robot.unwrap_or(base.upkeep.per_robot);
+
+        let objectives = &mut world.objectives;
+        objectives.delivered = self.objectives.delivered.unwrap_or(objectives.delivered);
+        objectives.explored_percent = self.objectives.explored_percent.unwrap_or(objectives.explored_percent);
+        objectives.survive_ticks = self.objectives.survive_ticks.unwrap_or(objectives.survive_ticks);
+        objectives.deadline = self.objectives.deadline.unwrap_or(objectives.deadline);
+        objectives.keep_energy = self.objectives.keep_energy.unwrap_or(objectives.keep_energy);
+
+        scenario.tick_rate = self.simulation.tick_rate.unwrap_or(scenario.tick_rate);
+        Ok(())
+    }
+
+    /// Fichier décrivant explicitement chaque valeur du scénario.
+    fn from_scenario(scenario: &Scenario) -> Self {
+        let world = &scenario.world;
+        let terrain = &world.terrain;
+        let count = |robot_type: RobotType| {
+            world.robots.iter().filter(|(other, _)| *other == robot_type).map(|&(_, count)| count).sum()
+        };
+
+        Self {
+            world: WorldSection {
+                width: Some(world.width),
+                height: Some(world.height),
+                seed: Some(world.seed),
+                generator: Some(world.generator),
+            },
+            terrain: TerrainSection {
+                scale: Some(terrain.scale),
+                obstacle_threshold: Some(terrain.obstacle_threshold),
+                resource_threshold: Some(terrain.resource_threshold),
+                energy_percent: Some(terrain.energy_percent),
+                mineral_percent: Some(terrain.mineral_percent),
+                science_percent: Some(terrain.science_percent),
+            },
+            robots: RobotType::ALL.iter().map(|&robot_type| (robot_type.key().to_string(), count(robot_type))).collect(),
+            stats: RobotType::ALL.iter()
+                .map(|&robot_type| {
+                    let stats = world.stats_for(robot_type);
+                    (robot_type.key().to_string(), StatsSection {
+                        ticks_per_tile: Some(stats.ticks_per_tile),
+                        mining_ticks: Some(stats.mining_ticks),
+                        unloading_ticks: Some(stats.unloading_ticks),
+                        sensor_radius: Some(stats.sensor_radius),
+                        cargo_capacity: Some(stats.cargo_capacity),
+                        max_hp: Some(stats.max_hp),
+                    })
+                })
+                .collect(),
+            base: BaseSection {
+                comms_range: Some(world.base.comms_range),
+                autobuild: Some(world.base.autobuild),
+                starting_resources: Some(world.base.starting_resources),
+                upkeep_life_support: Some(world.base.upkeep.life_support),
+                upkeep_per_robot: Some(world.base.upkeep.per_robot),
+            },
+            simulation: SimulationSection { tick_rate: Some(scenario.tick_rate) },
+            objectives: ObjectivesSection {
+                delivered: Some(world.objectives.delivered),
+                explored_percent: Some(world.objectives.explored_percent),
+                survive_ticks: Some(world.objectives.survive_ticks),
+                deadline: Some(world.objectives.deadline),
+                keep_energy: Some(world.objectives.keep_energy),
+            },
+        }
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod config_tests {
+    use super::super::{ConfigError, ResourceAmounts, Scenario, SetupError, WorldConfig};
+    use crate::map::MapGenerator;
+    use crate::game::GameState;
+    use crate::robot::RobotType;
+
+    #[test]
+    fn test_invalid_world_is_reported() {
+        let tiny = WorldConfig { width: 4, ..WorldConfig::default() };
+        assert_eq!(tiny.validate(), Err(SetupError::InvalidMapSize { dimension: "largeur", value: 4 }));
+        assert!(GameState::with_config(&tiny).is_err());
+
+        let empty = WorldConfig { robots: vec![(RobotType::Miner, 0)], ..WorldConfig::default() };
+        assert_eq!(empty.validate(), Err(SetupError::EmptySwarm));
+
+        let huge = WorldConfig {
+            robots: vec![(RobotType::Explorer, 100_000_000), (RobotType::Miner, usize::MAX)],
+            ..WorldConfig::default()
+        };
+        assert_eq!(huge.validate(), Err(SetupError::SwarmTooLarge(usize::MAX)));
+        assert!(GameState::with_config(&huge).is_err());
+    }
+
+    #[test]
+    fn test_game_uses_configured_swarm() {
+        let config = WorldConfig {
+            width: 30,
+            height: 20,
+            robots: vec![(RobotType::Explorer, 2), (RobotType::Hauler, 1)],
+            ..WorldConfig::default()
+        };
+        let game = GameState::with_config(&config).unwrap();
+
+        assert_eq!(game.get_map().len(), 20);
+        assert_eq!(game.get_map()[0].len(), 30);
+        let types: Vec<RobotType> = game.get_robots().iter().map(|robot| robot.robot_type).collect();
+        assert_eq!(types, vec![RobotType::Explorer, RobotType::Explorer, RobotType::Hauler]);
+    }
+
+    #[test]
+    fn test_scenario_merges_with_defaults() {
+        let scenario = Scenario::from_toml(r#"
+            [world]
+            generator = "caves"
+
+            [terrain]
+            scale = 0.05
+
+            [robots]
+            miner = 6
+            relay = 0
+
+            [stats.miner]
+            cargo_capacity = 3
+
+            [base]
+            starting_resources = { mineral = 20 }
+
+            [simulation]
+            tick_rate = 25.0
+
+            [objectives]
+            explored_percent = 75.0
+        "#).unwrap();
+        let world = &scenario.world;
+
+        assert_eq!(world.width, WorldConfig::default().width);
+        assert_eq!(world.generator, MapGenerator::Caves);
+        assert_eq!(world.terrain.scale, 0.05);
+        assert_eq!(world.terrain.obstacle_threshold, MapGenerator::Caves.params().obstacle_threshold);
+        assert!(world.robots.contains(&(RobotType::Miner, 6)));
+        assert!(world.robots.contains(&(RobotType::Relay, 0)));
+        assert!(world.robots.contains(&(RobotType::Explorer, 4)));
+        let miner = world.stats_for(RobotType::Miner);
+        assert_eq!(miner.cargo_capacity, 3);
+        assert_eq!(miner.mining_ticks, RobotType::Miner.base_stats().mining_ticks);
+        assert_eq!(world.base.starting_resources, ResourceAmounts { mineral: 20, ..ResourceAmounts::default() });
+        assert_eq!(scenario.tick_rate, 25.0);
+        assert_eq!(world.objectives.explored_percent, 75.0);
+    }
+
+    #[test]
+    fn test_scenario_rejects_unknown_keys() {
+        assert!(matches!(Scenario::from_toml("[world]\nwidht = 50\n"), Err(ConfigError::Parse(_))));
+        assert!(matches!(Scenario::from_toml("[wrold]\n"), Err(ConfigError::Parse(_))));
+        assert_eq!(
+            Scenario::from_toml("[robots]\ndrone = 2\n"),
+            Err(ConfigError::UnknownRobotType { section: "robots", key: "drone".to_string() })
+        );
+        assert!(matches!(Scenario::from_toml("[world]\nwidth = 2\n"), Err(ConfigError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_scenario_rejects_non_finite_rates() {
+        for value in ["nan", "inf", "-1.0", "1e-300", "1e9"] {
+            let text = format!("[simulation]\ntick_rate = {}\n", value);
+            assert!(matches!(Scenario::from_toml(&text), Err(ConfigError::Invalid(_))), "tick_rate = {}", value);
+        }
+        for value in ["nan", "inf", "0.0"] {
+            let text = format!("[terrain]\nscale = {}\n", value);
+            assert!(matches!(Scenario::from_toml(&text), Err(ConfigError::Invalid(_))), "scale = {}", value);
+        }
+    }
+
+    #[test]
+    fn test_scenario_bounds_robot_stats() {
+        for key in ["max_hp = 4294967295", "sensor_radius = 5000000000", "cargo_capacity = 100000"] {
+            let text = format!("[stats.miner]\n{}\n", key);
+            assert!(matches!(Scenario::from_toml(&text), Err(ConfigError::Invalid(_))), "{}", key);
+        }
+        assert!(Scenario::from_toml("[stats.explorer]\nsensor_radius = 1000\nmax_hp = 1000000\n").is_ok());
+    }
+
+    #[test]
+    fn test_scenario_bounds_upkeep() {
+        assert!(Scenario::from_toml("[base]\nupkeep_life_support = 100000\n").is_ok());
+        assert!(matches!(
+            Scenario::from_toml("[base]\nupkeep_per_robot = 4000000000\n"),
+            Err(ConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_dumped_scenario_reads_back_identically() {
+        let scenario = Scenario::from_toml("[world]\nseed = 12\n\n[stats.hauler]\nmax_hp = 10\n").unwrap();
+        assert_eq!(Scenario::from_toml(&scenario.to_toml()), Ok(scenario));
+    }
+
+    #[test]
+    fn test_victory_conditions() {
+        let mut config = WorldConfig { width: 30, height: 30, ..WorldConfig::default() };
+        config.objectives.explored_percent = 1.0;
+        let mut game = GameState::with_config(&config).unwrap();
+        game.update();
+        assert_eq!(game.get_victory_tick(), Some(0));
+
+        config.objectives.delivered.science = 1000;
+        let mut game = GameState::with_config(&config).unwrap();
+        game.update();
+        assert_eq!(game.get_victory_tick(), None);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::base::{MAX_UPKEEP, NoBaseError, Upkeep};
+use crate::map::{MapGenerator, TerrainParams};
+use crate::robot::{RobotStats, RobotType};
+
+// Bornes acceptées pour les dimensions de la carte
+pub const MIN_MAP_SIZE: usize = 10;
+pub const MAX_MAP_SIZE: usize = 1000;
+// Bornes des caractéristiques des robots réglables dans un scénario
+pub const MAX_ROBOT_HP: u32 = 1_000_000;
+pub const MAX_SENSOR_RADIUS: usize = MAX_MAP_SIZE;
+pub const MAX_CARGO_CAPACITY: u32 = 1000;
+// Nombre maximal de robots dans la flotte de départ
+pub const MAX_FLEET_SIZE: usize = 1000;
+// Portée radio par défaut autour de la base, en cases
+pub const DEFAULT_COMMS_RANGE: usize = 10;
+
+/// Paramètres de création d'une partie : carte, flotte de départ, base et objectifs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldConfig {
+    pub width: usize,
+    pub height: usize,
+    pub seed: u32,
+    pub generator: MapGenerator,
+    pub terrain: TerrainParams, // Réglages du générateur, par défaut ceux de `generator`
+    pub robots: Vec<(RobotType, usize)>, // Nombre de robots de chaque type au départ
+    pub stats: HashMap<RobotType, RobotStats>, // Caractéristiques remplaçant celles du type
+    pub base: BaseSettings,
+    pub objectives: ObjectiveSettings,
+}
+
+impl WorldConfig {
+    /// Flotte de départ par défaut.
+    pub fn default_robots() -> Vec<(RobotType, usize)> {
+        vec![
+            (RobotType::Explorer, 4),
+            (RobotType::Miner, 3),
+            (RobotType::EnergyCollector, 2),
+            (RobotType::Scientist, 2),
+            (RobotType::Rescue, 1),
+            (RobotType::Builder, 1),
+            (RobotType::Relay, 1),
+        ]
+    }
+
+    /// Caractéristiques de base des robots de ce type dans cette partie.
+    pub fn stats_for(&self, robot_type: RobotType) -> RobotStats {
+        self.stats.get(&robot_type).copied().unwrap_or_else(|| robot_type.base_stats())
+    }
+
+    /// Change de générateur et reprend ses réglages de terrain.
+    pub fn set_generator(&mut self, generator: MapGenerator) {
+        self.generator = generator;
+        self.terrain = generator.params();
+    }
+
+    pub fn validate(&self) -> Result<(), SetupError> {
+        for (dimension, value) in [("largeur", self.width), ("hauteur", self.height)] {
+            if !(MIN_MAP_SIZE..=MAX_MAP_SIZE).contains(&value) {
+                return Err(SetupError::InvalidMapSize { dimension, value });
+            }
+        }
+        if self.robots.iter().all(|&(_, count)| count == 0) {
+            return Err(SetupError::EmptySwarm);
+        }
+        let fleet_size = self.robots.iter().fold(0usize, |total, &(_, count)| total.saturating_add(count));
+        if fleet_size > MAX_FLEET_SIZE {
+            return Err(SetupError::SwarmTooLarge(fleet_size));
+        }
+
+        let terrain = &self.terrain;
+        if !(terrain.scale.is_finite() && terrain.scale > 0.0) {
+            return Err(SetupError::invalid("terrain.scale", "doit être un nombre fini strictement positif"));
+        }
+        if terrain.energy_percent + terrain.mineral_percent + terrain.science_percent > 100 {
+            return Err(SetupError::invalid("terrain", "la somme des pourcentages de ressources dépasse 100"));
+        }
+        let upkeep = &self.base.upkeep;
+        for (name, value) in [("base.upkeep_life_support", upkeep.life_support), ("base.upkeep_per_robot", upkeep.per_robot)] {
+            if value > MAX_UPKEEP {
+                return Err(SetupError::invalid(name, &format!("au plus {} millièmes d'Énergie par tick", MAX_UPKEEP)));
+            }
+        }
+        for (robot_type, stats) in &self.stats {
+            if stats.ticks_per_tile == 0 || stats.max_hp == 0 {
+                return Err(SetupError::invalid(
+                    &format!("stats.{}", robot_type.key()),
+                    "ticks_per_tile et max_hp doivent valoir au moins 1",
+                ));
+            }
+            if stats.max_hp > MAX_ROBOT_HP || stats.sensor_radius > MAX_SENSOR_RADIUS || stats.cargo_capacity > MAX_CARGO_CAPACITY {
+                return Err(SetupError::invalid(
+                    &format!("stats.{}", robot_type.key()),
+                    &format!(
+                        "max_hp, sensor_radius et cargo_capacity valent au plus {}, {} et {}",
+                        MAX_ROBOT_HP, MAX_SENSOR_RADIUS, MAX_CARGO_CAPACITY
+                    ),
+                ));
+            }
+        }
+        let objectives = &self.objectives;
+        if !(0.0..=100.0).contains(&objectives.explored_percent) {
+            return Err(SetupError::invalid("objectives.explored_percent", "attendu entre 0 et 100"));
+        }
+        if objectives.keep_energy && self.base.starting_resources.energy == 0 {
+            return Err(SetupError::invalid(
+                "objectives.keep_energy",
+                "la base doit disposer d'énergie au départ (base.starting_resources)",
+            ));
+        }
+        if objectives.deadline > 0 {
+            if objectives.delivered == ResourceAmounts::default() && objectives.explored_percent == 0.0 {
+                return Err(SetupError::invalid("objectives.deadline", "sans livraison ni exploration à atteindre"));
+            }
+            if objectives.survive_ticks > objectives.deadline {
+                return Err(SetupError::invalid("objectives.survive_ticks", "dépasse objectives.deadline"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            height: 80,
+            seed: 1337,
+            generator: MapGenerator::default(),
+            terrain: MapGenerator::default().params(),
+            robots: Self::default_robots(),
+            stats: HashMap::new(),
+            base: BaseSettings::default(),
+            objectives: ObjectiveSettings::default(),
+        }
+    }
+}
+
+/// Quantité de chaque ressource ; les ressources absentes d'un fichier valent 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct ResourceAmounts {
+    pub energy: u32,
+    pub mineral: u32,
+    pub science: u32,
+}
+
+/// Réglages de la base en début de partie.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseSettings {
+    pub comms_range: usize,
+    pub starting_resources: ResourceAmounts,
+    pub autobuild: bool,
+    pub upkeep: Upkeep,
+}
+
+impl Default for BaseSettings {
+    fn default() -> Self {
+        Self {
+            comms_range: DEFAULT_COMMS_RANGE,
+            starting_resources: ResourceAmounts::default(),
+            autobuild: false,
+            upkeep: Upkeep::default(),
+        }
+    }
+}
+
+/// Objectifs de la partie ; sans aucun objectif, la partie n'a pas de fin.
+/// La victoire demande de remplir toutes les conditions de livraison, d'exploration
+/// et de survie ; la défaite survient si l'échéance passe avant, ou si l'énergie
+/// de la base tombe à zéro quand `keep_energy` est demandé. Une valeur nulle désactive la condition.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ObjectiveSettings {
+    pub delivered: ResourceAmounts, // Ressources à livrer à la base
+    pub explored_percent: f64,      // Part de la carte à explorer
+    pub survive_ticks: u64,         // Ticks à tenir
+    pub deadline: u64,              // Tick avant lequel livraisons et exploration doivent être faites
+    pub keep_energy: bool,          // Garder de l'énergie en stock à la base
+}
+
+impl ObjectiveSettings {
+    pub fn is_set(&self) -> bool {
+        self.delivered != ResourceAmounts::default()
+            || self.explored_percent > 0.0
+            || self.survive_ticks > 0
+            || self.keep_energy
+    }
+}
+
+/// Partie impossible à créer avec la configuration demandée.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupError {
+    InvalidMapSize { dimension: &'static str, value: usize },
+    EmptySwarm,
+    SwarmTooLarge(usize),
+    InvalidParameter { name: String, reason: String },
+    NoBase(NoBaseError),
+}
+
+impl SetupError {
+    fn invalid(name: &str, reason: &str) -> Self {
+        SetupError::InvalidParameter { name: name.to_string(), reason: reason.to_string() }
+    }
+}
+
+impl fmt::Display for SetupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetupError::InvalidMapSize { dimension, value } => write!(
+                f,
+                "{} de carte invalide : {} (attendu entre {} et {})",
+                dimension, value, MIN_MAP_SIZE, MAX_MAP_SIZE
+            ),
+            SetupError::EmptySwarm => write!(f, "la flotte de départ doit compter au moins un robot"),
+            SetupError::SwarmTooLarge(size) => {
+                write!(f, "flotte de départ trop grande : {} robots (au plus {})", size, MAX_FLEET_SIZE)
+            }
+            SetupError::InvalidParameter { name, reason } => write!(f, "paramètre {} invalide : {}", name, reason),
+            SetupError::NoBase(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<NoBaseError> for SetupError {
+    fn from(error: NoBaseError) -> Self {
+        SetupError::NoBase(error)
+    }
+}
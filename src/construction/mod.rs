@@ -0,0 +1,7 @@
+pub mod planning;
+pub mod structure;
+#[cfg(test)]
+mod tests;
+
+pub use planning::{densest_cluster, shortest_path, suggest_depot_site};
+pub use structure::{BuildError, BuildOrder, Structure};
\ No newline at end of file
@@ -0,0 +1,83 @@
+use crate::map::{distance_field, tile::Tile};
+
+// Distance minimale (Manhattan) entre un dépôt et la base
+const MIN_DEPOT_DISTANCE_FROM_BASE: usize = 15;
+// Distance minimale entre deux dépôts
+const MIN_DEPOT_SPACING: usize = 12;
+
+/// Plus court chemin de `from` à `to` (extrémités comprises), vide si `to` est inaccessible.
+pub fn shortest_path(map: &[Vec<Tile>], from: (usize, usize), to: (usize, usize)) -> Vec<(usize, usize)> {
+    if !map[to.1][to.0].is_walkable() {
+        return Vec::new();
+    }
+    let distances = distance_field(map, to);
+    let Some(mut remaining) = distances[from.1][from.0] else { return Vec::new() };
+
+    // On descend le champ de distances depuis le départ jusqu'à la cible
+    let mut path = vec![from];
+    let (mut x, mut y) = from;
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+    while remaining > 0 {
+        let next = directions.iter()
+            .map(|(dx, dy)| ((x as isize + dx) as usize, (y as isize + dy) as usize))
+            .find(|&(nx, ny)| {
+                distances.get(ny).and_then(|row| row.get(nx)).copied().flatten() == Some(remaining - 1)
+            });
+        let Some((nx, ny)) = next else { break };
+        (x, y) = (nx, ny);
+        path.push((x, y));
+        remaining -= 1;
+    }
+    path
+}
+
+/// Position qui a le plus d'autres positions à moins de `radius` cases (distance de Manhattan).
+/// À égalité, la première de la liste l'emporte.
+pub fn densest_cluster(positions: &[(usize, usize)], radius: usize) -> Option<(usize, usize)> {
+    positions.iter()
+        .copied()
+        .enumerate()
+        .max_by_key(|&(index, (x, y))| {
+            let neighbours = positions.iter()
+                .filter(|&&(ox, oy)| ox.abs_diff(x) + oy.abs_diff(y) <= radius)
+                .count();
+            (neighbours, std::cmp::Reverse(index))
+        })
+        .map(|(_, position)| position)
+}
+
+/// Emplacement de dépôt : case vide la plus proche du plus gros amas de ressources
+/// assez loin de la base et des dépôts existants.
+pub fn suggest_depot_site(
+    map: &[Vec<Tile>],
+    resources: &[(usize, usize)],
+    base: (usize, usize),
+    depots: &[(usize, usize)],
+    cluster_radius: usize,
+) -> Option<(usize, usize)> {
+    let far_enough = |&(x, y): &(usize, usize), (ox, oy): (usize, usize), min: usize| {
+        x.abs_diff(ox) + y.abs_diff(oy) >= min
+    };
+    let candidates: Vec<(usize, usize)> = resources.iter()
+        .copied()
+        .filter(|position| far_enough(position, base, MIN_DEPOT_DISTANCE_FROM_BASE))
+        .filter(|position| depots.iter().all(|&depot| far_enough(position, depot, MIN_DEPOT_SPACING)))
+        .collect();
+    let (center_x, center_y) = densest_cluster(&candidates, cluster_radius)?;
+
+    let distances = distance_field(map, (center_x, center_y));
+    let mut best: Option<(u32, usize, usize)> = None;
+    for (y, row) in map.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            if *tile != Tile::Empty {
+                continue;
+            }
+            if let Some(distance) = distances[y][x] {
+                if best.is_none_or(|(best_distance, _, _)| distance < best_distance) {
+                    best = Some((distance, x, y));
+                }
+            }
+        }
+    }
+    best.map(|(_, x, y)| (x, y))
+}
@@ -0,0 +1,107 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+use crate::robot::RobotId;
+
+// Nombre maximal d'ordres de construction en attente
+pub const MAX_BUILD_ORDERS: usize = 64;
+
+/// Ouvrage qu'un bâtisseur peut construire sur la carte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Structure {
+    Road,
+    Bridge,
+    Beacon,
+    Depot,
+}
+
+impl Structure {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Structure::Road => "Route",
+            Structure::Bridge => "Pont",
+            Structure::Beacon => "Balise",
+            Structure::Depot => "Dépôt",
+        }
+    }
+
+    pub fn tile(&self) -> Tile {
+        match self {
+            Structure::Road => Tile::Road,
+            Structure::Bridge => Tile::Bridge,
+            Structure::Beacon => Tile::Beacon,
+            Structure::Depot => Tile::Depot,
+        }
+    }
+
+    /// Terrain sur lequel l'ouvrage doit être posé.
+    pub fn required_ground(&self) -> Tile {
+        match self {
+            Structure::Road | Structure::Beacon | Structure::Depot => Tile::Empty,
+            Structure::Bridge => Tile::Obstacle,
+        }
+    }
+
+    pub fn cost(&self) -> Vec<(Tile, u32)> {
+        match self {
+            Structure::Road => vec![(Tile::Mineral, 1)],
+            Structure::Bridge => vec![(Tile::Mineral, 4), (Tile::Energy, 1)],
+            Structure::Beacon => vec![(Tile::Mineral, 3), (Tile::Energy, 2), (Tile::Science, 1)],
+            Structure::Depot => vec![(Tile::Mineral, 8)],
+        }
+    }
+
+    pub fn build_ticks(&self) -> u32 {
+        match self {
+            Structure::Road => 3,
+            Structure::Bridge => 12,
+            Structure::Beacon => 10,
+            Structure::Depot => 20,
+        }
+    }
+}
+
+/// Chantier en attente d'un bâtisseur. Le coût est payé à la commande.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildOrder {
+    pub structure: Structure,
+    pub x: usize,
+    pub y: usize,
+    pub assigned_robot_id: Option<RobotId>,
+}
+
+impl BuildOrder {
+    pub fn new(structure: Structure, x: usize, y: usize) -> Self {
+        Self { structure, x, y, assigned_robot_id: None }
+    }
+
+    /// Vrai tant que le terrain permet encore de construire l'ouvrage.
+    pub fn is_valid(&self, map: &[Vec<Tile>]) -> bool {
+        map.get(self.y)
+            .and_then(|row| row.get(self.x))
+            .is_some_and(|tile| *tile == self.structure.required_ground())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    OutOfBounds,
+    InvalidGround,
+    AlreadyPlanned,
+    QueueFull,
+    InsufficientResources,
+    NoSuitableSite,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::OutOfBounds => write!(f, "hors de la carte"),
+            BuildError::InvalidGround => write!(f, "terrain inadapté"),
+            BuildError::AlreadyPlanned => write!(f, "chantier déjà prévu"),
+            BuildError::QueueFull => write!(f, "trop de chantiers en attente"),
+            BuildError::InsufficientResources => write!(f, "ressources insuffisantes"),
+            BuildError::NoSuitableSite => write!(f, "aucun emplacement adapté"),
+        }
+    }
+}
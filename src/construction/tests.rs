@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod construction_tests {
+    use super::super::{BuildOrder, Structure, densest_cluster, shortest_path, suggest_depot_site};
+    use crate::map::tile::Tile;
+
+    #[test]
+    fn test_structure_ground() {
+        let map = vec![vec![Tile::Empty, Tile::Obstacle, Tile::Mineral]];
+
+        assert!(BuildOrder::new(Structure::Road, 0, 0).is_valid(&map));
+        assert!(!BuildOrder::new(Structure::Road, 1, 0).is_valid(&map));
+        assert!(BuildOrder::new(Structure::Bridge, 1, 0).is_valid(&map));
+        assert!(!BuildOrder::new(Structure::Beacon, 2, 0).is_valid(&map));
+        assert!(!BuildOrder::new(Structure::Beacon, 5, 0).is_valid(&map));
+    }
+
+    #[test]
+    fn test_structures_are_walkable() {
+        for structure in [Structure::Road, Structure::Bridge, Structure::Beacon] {
+            assert!(structure.tile().is_walkable());
+        }
+        assert!(Tile::Road.is_paved());
+        assert!(!Tile::Beacon.is_paved());
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let map = vec![
+            vec![Tile::Empty, Tile::Obstacle, Tile::Empty],
+            vec![Tile::Empty, Tile::Empty, Tile::Empty],
+        ];
+
+        let path = shortest_path(&map, (0, 0), (2, 0));
+        assert_eq!(path, vec![(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)]);
+        assert_eq!(shortest_path(&map, (0, 0), (1, 0)), Vec::new());
+    }
+
+    #[test]
+    fn test_densest_cluster() {
+        let positions = vec![(0, 0), (20, 20), (21, 20), (20, 22), (40, 0)];
+
+        assert_eq!(densest_cluster(&positions, 3), Some((20, 20)));
+        assert_eq!(densest_cluster(&[], 3), None);
+    }
+
+    #[test]
+    fn test_depot_site_near_far_cluster() {
+        let mut map = vec![vec![Tile::Empty; 40]; 5];
+        map[2][30] = Tile::Mineral;
+        map[2][31] = Tile::Mineral;
+        map[2][3] = Tile::Energy;
+        let resources = vec![(3, 2), (30, 2), (31, 2)];
+
+        // L'amas proche de la base est ignoré ; le dépôt va sur la case vide voisine
+        let site = suggest_depot_site(&map, &resources, (0, 2), &[], 4);
+        assert_eq!(site, Some((30, 1)));
+
+        // Un dépôt existe déjà près de l'amas éloigné
+        assert_eq!(suggest_depot_site(&map, &resources, (0, 2), &[(29, 2)], 4), None);
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use super::event::GameEvent;
+
+/// Événement reçu par un abonné, avec le tick où il s'est produit.
+pub type EventReceiver = Receiver<(u64, GameEvent)>;
+
+/// File des événements du tick en cours et abonnés qui les reçoivent.
+/// Chaque abonné a son propre canal : il lit ses événements quand il le souhaite,
+/// sans que la simulation ait à le connaître.
+#[derive(Debug, Default)]
+pub struct EventBus {
+    queue: Vec<GameEvent>,
+    subscribers: Vec<Sender<(u64, GameEvent)>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn emit(&mut self, event: GameEvent) {
+        self.queue.push(event);
+    }
+
+    /// Événements émis depuis la dernière distribution.
+    pub fn pending(&self) -> &[GameEvent] {
+        &self.queue
+    }
+
+    pub fn subscribe(&mut self) -> EventReceiver {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Envoie les événements en file à chaque abonné puis vide la file.
+    /// Un abonné dont le récepteur a été abandonné est oublié.
+    pub fn flush(&mut self, tick: u64) {
+        let events = std::mem::take(&mut self.queue);
+        self.subscribers.retain(|subscriber| {
+            events.iter().all(|event| subscriber.send((tick, event.clone())).is_ok())
+        });
+    }
+}
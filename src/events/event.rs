@@ -0,0 +1,62 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::game::DiagnosticKind;
+use crate::map::tile::Tile;
+use crate::objectives::Outcome;
+use crate::robot::{RobotId, RobotType};
+
+/// Fait marquant de la simulation, publié à la fin du tick où il s'est produit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum GameEvent {
+    /// Ressource signalée à la base pour la première fois
+    ResourceDiscovered { x: usize, y: usize, resource: Tile },
+    /// Ressource confiée à un robot par l'allocation
+    ResourceAssigned { robot_id: RobotId, x: usize, y: usize, resource: Tile },
+    /// Ressource extraite et chargée dans la soute
+    ResourcePickedUp { robot_id: RobotId, x: usize, y: usize, resource: Tile },
+    /// Soute vidée à la base, ou dans un dépôt si `depot` est vrai
+    CargoDelivered { robot_id: RobotId, x: usize, y: usize, resources: Vec<Tile>, depot: bool },
+    /// Robot qui renonce à sa ressource : inaccessible ou bloqué
+    RobotStuck { robot_id: RobotId, x: usize, y: usize, reason: DiagnosticKind },
+    /// Robot sorti de la file de production
+    RobotBuilt { robot_id: RobotId, robot_type: RobotType },
+    /// Entretien de la base impayé faute d'énergie, ou de nouveau payé
+    Brownout { active: bool },
+    /// Objectifs remplis ou partie perdue
+    GameOver { outcome: Outcome },
+}
+
+impl fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameEvent::ResourceDiscovered { x, y, resource } => {
+                write!(f, "{} découvert en ({}, {})", resource.to_char(), x, y)
+            }
+            GameEvent::ResourceAssigned { robot_id, x, y, resource } => {
+                write!(f, "{} en ({}, {}) confié à {}", resource.to_char(), x, y, robot_id)
+            }
+            GameEvent::ResourcePickedUp { robot_id, x, y, resource } => {
+                write!(f, "{} a extrait {} en ({}, {})", robot_id, resource.to_char(), x, y)
+            }
+            GameEvent::CargoDelivered { robot_id, resources, depot, .. } => write!(
+                f,
+                "{} a livré {} ressource(s) {}",
+                robot_id,
+                resources.len(),
+                if *depot { "au dépôt" } else { "à la base" }
+            ),
+            GameEvent::RobotStuck { robot_id, x, y, reason } => {
+                let reason = match reason {
+                    DiagnosticKind::Unreachable => "inaccessible",
+                    DiagnosticKind::Stalled => "bloqué",
+                };
+                write!(f, "{} abandonne ({}, {}) : {}", robot_id, x, y, reason)
+            }
+            GameEvent::RobotBuilt { robot_id, robot_type } => write!(f, "{} {} construit", robot_type.name(), robot_id),
+            GameEvent::Brownout { active: true } => write!(f, "Délestage : plus d'énergie pour l'entretien"),
+            GameEvent::Brownout { active: false } => write!(f, "Fin du délestage"),
+            GameEvent::GameOver { outcome } => write!(f, "{}", outcome),
+        }
+    }
+}
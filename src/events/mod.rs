@@ -0,0 +1,7 @@
+pub mod bus;
+pub mod event;
+#[cfg(test)]
+mod tests;
+
+pub use bus::{EventBus, EventReceiver};
+pub use event::GameEvent;
\ No newline at end of file
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod events_tests {
+    use super::super::{EventBus, GameEvent};
+    use crate::config::{BaseSettings, ResourceAmounts, WorldConfig};
+    use crate::game::GameState;
+    use crate::map::tile::Tile;
+    use crate::robot::{RobotId, RobotType};
+    use crate::save::{from_json, to_json};
+
+    #[test]
+    fn test_bus_delivers_to_each_subscriber() {
+        let mut bus = EventBus::new();
+        let first = bus.subscribe();
+        let second = bus.subscribe();
+        let event = GameEvent::RobotBuilt { robot_id: RobotId(4), robot_type: RobotType::Miner };
+
+        bus.emit(event.clone());
+        assert_eq!(bus.pending().len(), 1);
+        bus.flush(3);
+        assert!(bus.pending().is_empty());
+        assert_eq!(first.try_iter().collect::<Vec<_>>(), vec![(3, event.clone())]);
+        assert_eq!(second.try_iter().collect::<Vec<_>>(), vec![(3, event.clone())]);
+
+        // Un abonné parti est oublié au premier envoi qui échoue
+        drop(second);
+        bus.emit(event.clone());
+        bus.flush(4);
+        assert_eq!(bus.subscriber_count(), 1);
+        assert_eq!(first.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn test_game_publishes_events_each_tick() {
+        let config = WorldConfig {
+            width: 40,
+            height: 40,
+            base: BaseSettings {
+                starting_resources: ResourceAmounts { energy: 20, mineral: 20, science: 20 },
+                ..BaseSettings::default()
+            },
+            ..WorldConfig::default()
+        };
+        let mut game = GameState::with_config(&config).unwrap();
+        let receiver = game.subscribe();
+        game.queue_robot(RobotType::Explorer).unwrap();
+        for _ in 0..200 {
+            game.update();
+        }
+
+        let events: Vec<(u64, GameEvent)> = receiver.try_iter().collect();
+        assert!(events.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        let has = |matches: fn(&GameEvent) -> bool| events.iter().any(|(_, event)| matches(event));
+        assert!(has(|event| matches!(event, GameEvent::ResourceDiscovered { .. })));
+        assert!(has(|event| matches!(event, GameEvent::ResourceAssigned { .. })));
+        assert!(has(|event| matches!(event, GameEvent::ResourcePickedUp { .. })));
+        assert!(has(|event| matches!(event, GameEvent::RobotBuilt { robot_type: RobotType::Explorer, .. })));
+
+        // Les livraisons publiées correspondent au total livré à la base
+        let delivered: usize = events.iter()
+            .filter_map(|(_, event)| match event {
+                GameEvent::CargoDelivered { resources, depot: false, .. } => Some(resources.len()),
+                _ => None,
+            })
+            .sum();
+        let total: u32 = [Tile::Energy, Tile::Mineral, Tile::Science].iter()
+            .map(|tile| game.get_delivered().get(tile).copied().unwrap_or(0))
+            .sum();
+        assert!(delivered > 0);
+        assert_eq!(delivered, total as usize);
+
+        // Les abonnés suivent la partie qui remplace celle-ci
+        let mut loaded = from_json(&to_json(&game)).unwrap();
+        loaded.take_subscribers(&mut game);
+        for _ in 0..50 {
+            loaded.update();
+        }
+        let later: Vec<(u64, GameEvent)> = receiver.try_iter().collect();
+        assert!(!later.is_empty());
+        assert!(later.iter().all(|(tick, _)| *tick >= 200));
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::{HashSet, VecDeque};
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+
+// Distance minimale (Manhattan) entre deux cibles revendiquées par des explorateurs
+pub const CLAIM_SPACING: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExplorationStrategy {
+    RandomWalk,
+    Frontier,
+}
+
+impl ExplorationStrategy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExplorationStrategy::RandomWalk => "Marche aléatoire",
+            ExplorationStrategy::Frontier => "Frontière",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            ExplorationStrategy::RandomWalk => ExplorationStrategy::Frontier,
+            ExplorationStrategy::Frontier => ExplorationStrategy::RandomWalk,
+        }
+    }
+}
+
+fn is_claimed(pos: (usize, usize), claims: &[(usize, usize)]) -> bool {
+    claims.iter().any(|&(cx, cy)| pos.0.abs_diff(cx) + pos.1.abs_diff(cy) < CLAIM_SPACING)
+}
+
+/// Cherche par parcours en largeur la case inexplorée accessible la plus proche
+/// qui borde la zone déjà explorée, en évitant les cibles revendiquées par les autres.
+pub fn nearest_frontier(
+    map: &[Vec<Tile>],
+    explored: &[Vec<bool>],
+    start: (usize, usize),
+    claims: &[(usize, usize)],
+) -> Option<(usize, usize)> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut fallback = None;
+
+    queue.push_back(start);
+    visited.insert(start);
+
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    while let Some((x, y)) = queue.pop_front() {
+        if !explored[y][x] {
+            // Case frontière : on ne s'enfonce pas plus loin dans l'inconnu
+            if !is_claimed((x, y), claims) {
+                return Some((x, y));
+            }
+            fallback.get_or_insert((x, y));
+            continue;
+        }
+
+        for (dx, dy) in directions {
+            let new_x = (x as isize + dx) as usize;
+            let new_y = (y as isize + dy) as usize;
+
+            if new_y < map.len() && new_x < map[new_y].len() {
+                let pos = (new_x, new_y);
+                if !visited.contains(&pos) && map[new_y][new_x].is_walkable() {
+                    visited.insert(pos);
+                    queue.push_back(pos);
+                }
+            }
+        }
+    }
+
+    // Toutes les frontières sont revendiquées : on partage la plus proche
+    fallback
+}
+
+/// Proportion des cases de la carte déjà explorées, entre 0.0 et 1.0.
+pub fn coverage(explored: &[Vec<bool>]) -> f64 {
+    let total: usize = explored.iter().map(|row| row.len()).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let seen = explored.iter().flatten().filter(|&&e| e).count();
+    seen as f64 / total as f64
+}
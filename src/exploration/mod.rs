@@ -0,0 +1,5 @@
+pub mod frontier;
+#[cfg(test)]
+mod tests;
+
+pub use frontier::{ExplorationStrategy, coverage, nearest_frontier};
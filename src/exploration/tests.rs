@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod frontier_tests {
+    use super::super::frontier::{coverage, nearest_frontier, ExplorationStrategy};
+    use crate::map::tile::Tile;
+
+    fn open_map(width: usize, height: usize) -> Vec<Vec<Tile>> {
+        vec![vec![Tile::Empty; width]; height]
+    }
+
+    #[test]
+    fn test_nearest_frontier() {
+        let map = open_map(5, 1);
+        let explored = vec![vec![true, true, false, false, false]];
+
+        assert_eq!(nearest_frontier(&map, &explored, (0, 0), &[]), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_frontier_skips_obstacles() {
+        let map = vec![vec![Tile::Empty, Tile::Obstacle, Tile::Empty]];
+        let explored = vec![vec![true, false, false]];
+
+        assert_eq!(nearest_frontier(&map, &explored, (0, 0), &[]), None);
+    }
+
+    #[test]
+    fn test_frontier_avoids_claims() {
+        let map = open_map(30, 1);
+        let mut explored = vec![vec![false; 30]];
+        for tile in explored[0].iter_mut().take(25).skip(5) {
+            *tile = true;
+        }
+
+        assert_eq!(nearest_frontier(&map, &explored, (6, 0), &[]), Some((4, 0)));
+        assert_eq!(nearest_frontier(&map, &explored, (6, 0), &[(4, 0)]), Some((25, 0)));
+    }
+
+    #[test]
+    fn test_frontier_shared_when_all_claimed() {
+        let map = open_map(3, 1);
+        let explored = vec![vec![true, true, false]];
+
+        assert_eq!(nearest_frontier(&map, &explored, (0, 0), &[(2, 0)]), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_coverage() {
+        let explored = vec![vec![true, false], vec![true, true]];
+        assert_eq!(coverage(&explored), 0.75);
+    }
+
+    #[test]
+    fn test_strategy_toggle() {
+        assert_eq!(ExplorationStrategy::Frontier.toggled(), ExplorationStrategy::RandomWalk);
+        assert_eq!(ExplorationStrategy::RandomWalk.toggled(), ExplorationStrategy::Frontier);
+    }
+}
@@ -1,194 +1,1251 @@
-use std::collections::{HashMap};
-use rand::Rng;
-use crate::map::{generate_map, tile::Tile};
-use crate::robot::{Robot, RobotType, RobotState};
-use crate::base::{Base, find_all_base_positions, spawn_robots_in_base};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use crate::map::{distance_field, generate_map_with, label_regions, visible_tiles, tile::Tile};
+use crate::robot::{
+    MoveOutcome, Robot, RobotId, RobotRegistry, RobotState, RobotStats, RobotType,
+    Upgrade, UpgradeError, UpgradeSet, UpgradeTarget, WearConfig,
+};
+use crate::robot::upgrades::MAX_UPGRADE_LEVEL;
+use crate::base::{
+    AutobuildPolicy, Base, Depot, FabricationError, ProductionQueue, Upkeep, MILLI_ENERGY, find_all_base_positions, spawn_robots_in_base,
+};
+use crate::config::{ObjectiveSettings, SetupError, WorldConfig};
+use crate::events::{EventBus, EventReceiver, GameEvent};
+use crate::exploration::{ExplorationStrategy, coverage};
+use crate::objectives::{DefeatReason, GameOutcome, Objective, ObjectiveProgress, Outcome, Score};
+use crate::knowledge::{CommsNetwork, KnowledgeMap, RadioNode, Sighting};
+use crate::allocation::{AllocationMetrics, AllocationStrategy, allocate};
+use crate::construction::{BuildError, BuildOrder, Structure, densest_cluster, shortest_path, suggest_depot_site};
+use crate::construction::structure::MAX_BUILD_ORDERS;
+use crate::behavior::{Action, BehaviorRegistry, FrontierExplorer, RandomWalkExplorer, RobotBehavior, WorldView};
 
-#[derive(Debug, Clone)]
+// Nombre de ticks entre deux relevés de couverture
+const COVERAGE_SAMPLE_INTERVAL: u64 = 10;
+// Nombre de relevés de couverture conservés pour l'affichage
+const MAX_COVERAGE_HISTORY: usize = 500;
+// Nombre de ticks sans progrès avant qu'un robot n'abandonne sa ressource
+const STALL_TIMEOUT: u32 = 15;
+// Nombre de diagnostics conservés pour l'affichage
+const MAX_DIAGNOSTICS: usize = 50;
+// Nombre de relevés d'affectation conservés
+const MAX_ALLOCATION_HISTORY: usize = 500;
+// Portée radio d'une balise et d'un relais déployé
+const BEACON_COMMS_RANGE: usize = 8;
+const RELAY_COMMS_RANGE: usize = 10;
+// Rayon d'observation d'une balise
+const BEACON_SENSOR_RADIUS: usize = 5;
+// Rayon utilisé pour repérer les amas de ressources découvertes
+const CLUSTER_RADIUS: usize = 8;
+// Relevés du stock d'énergie servant à estimer son flux net, un par tick
+const ENERGY_FLOW_WINDOW: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveredResource {
     pub x: usize,
     pub y: usize,
     pub tile_type: Tile,
-    pub assigned_robot_id: Option<usize>,
+    pub assigned_robot_id: Option<RobotId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiagnosticKind {
+    /// Aucun chemin n'existe entre le robot et la ressource
+    Unreachable,
+    /// Le robot n'a pas progressé pendant `STALL_TIMEOUT` ticks
+    Stalled,
 }
 
+/// Signale une cible abandonnée par un robot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub tick: u64,
+    pub robot_id: RobotId,
+    pub x: usize,
+    pub y: usize,
+    pub kind: DiagnosticKind,
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct GameState {
     map: Vec<Vec<Tile>>,
-    robots: Vec<Robot>,
+    robots: RobotRegistry,
     base: Base,
     discovered_resources: Vec<DiscoveredResource>,
+    assignments: HashMap<RobotId, (usize, usize)>, // Robot -> position de la ressource assignée
+    explored: Vec<Vec<bool>>,
+    regions: Vec<Vec<Option<u32>>>,
+    unreachable_targets: BTreeSet<(u32, usize, usize)>, // (région du robot, x, y), trié pour des sauvegardes stables
+    diagnostics: VecDeque<Diagnostic>,
+    exploration_strategy: ExplorationStrategy,
+    allocation_strategy: AllocationStrategy,
+    allocation_history: VecDeque<AllocationMetrics>, // Uniquement les ticks avec des affectations
+    allocation_totals: (usize, u64), // (affectations, coût cumulé) depuis le début
+    coverage_history: VecDeque<(u64, f64)>,
+    tick: u64,
+    rng: ChaCha12Rng, // Même générateur que `StdRng`, mais sérialisable
+    #[serde(skip)]
+    behaviors: BehaviorRegistry, // Sans état : voir `restore_behaviors`
+    type_upgrades: HashMap<RobotType, UpgradeSet>, // Améliorations achetées pour tout un type
+    base_positions: Vec<(usize, usize)>, // Cases de base, points d'apparition des nouveaux robots
+    autobuild: Option<AutobuildPolicy>,
+    wear: WearConfig,
+    build_orders: VecDeque<BuildOrder>,
+    beacons: Vec<(usize, usize)>,
+    depots: Vec<Depot>,
+    comms_range: usize,
+    upkeep: Upkeep,
+    energy_history: VecDeque<u32>, // Stock d'énergie à la fin des derniers ticks
+    robot_stats: HashMap<RobotType, RobotStats>, // Caractéristiques de base propres à cette partie
+    objectives: ObjectiveSettings,
+    outcome: Option<GameOutcome>,
+    robots_destroyed: u32,
+    network: CommsNetwork,
+    report_delays: (u64, u64), // (ressources signalées, ticks cumulés entre observation et signalement)
+    delivered: HashMap<Tile, u32>, // Ressources déchargées à la base depuis le début
+    first_delivery_tick: Option<u64>,
+    robot_ticks: (u64, u64), // (ticks passés à attendre, ticks cumulés des robots en état de marche)
+    #[serde(skip)]
+    events: EventBus, // Abonnés de cette session, non sauvegardés
 }
 
 impl GameState {
+    /// Partie sur une carte Perlin avec la flotte par défaut.
+    ///
+    /// Panique si les dimensions sont hors des bornes ; voir `with_config`.
     pub fn new(width: usize, height: usize, seed: u32) -> Self {
-        let map = generate_map(width, height, seed);
-        
-        // Configuration des robots
-        let robot_counts = vec![
-            (RobotType::Explorer, 4),
-            (RobotType::Miner, 3),
-            (RobotType::EnergyCollector, 2),
-            (RobotType::Scientist, 2),
-        ];
-
-        let base_positions = find_all_base_positions(&map);
-        let robots = spawn_robots_in_base(&base_positions, &robot_counts);
-        let base = Base::new(width, height);
-
-        Self { 
-            map, 
-            robots, 
+        let config = WorldConfig { width, height, seed, ..WorldConfig::default() };
+        Self::with_config(&config).unwrap_or_else(|error| panic!("configuration invalide : {}", error))
+    }
+
+    pub fn with_config(config: &WorldConfig) -> Result<Self, SetupError> {
+        config.validate()?;
+        let (width, height, seed) = (config.width, config.height, config.seed);
+        let map = generate_map_with(&config.terrain, width, height, seed);
+
+        let base_positions = find_all_base_positions(&map)?;
+        let mut robots: RobotRegistry = spawn_robots_in_base(&base_positions, &config.robots).into_iter().collect();
+        for robot in robots.iter_mut() {
+            robot.knowledge = KnowledgeMap::new(width, height);
+            robot.set_base_stats(config.stats_for(robot.robot_type));
+        }
+        let mut base = Base::new(width, height);
+        let resources = config.base.starting_resources;
+        base.inventory.insert(Tile::Energy, resources.energy);
+        base.inventory.insert(Tile::Mineral, resources.mineral);
+        base.inventory.insert(Tile::Science, resources.science);
+
+        // La base est connue dès le départ
+        let explored = map.iter()
+            .map(|row| row.iter().map(|tile| *tile == Tile::Base).collect())
+            .collect();
+
+        let regions = label_regions(&map);
+
+        let mut game = Self {
+            map,
+            robots,
             base,
             discovered_resources: Vec::new(),
-        }
+            assignments: HashMap::new(),
+            explored,
+            regions,
+            unreachable_targets: BTreeSet::new(),
+            diagnostics: VecDeque::new(),
+            exploration_strategy: ExplorationStrategy::Frontier,
+            allocation_strategy: AllocationStrategy::Greedy,
+            allocation_history: VecDeque::new(),
+            allocation_totals: (0, 0),
+            coverage_history: VecDeque::new(),
+            tick: 0,
+            rng: ChaCha12Rng::seed_from_u64(seed as u64),
+            behaviors: BehaviorRegistry::default(),
+            type_upgrades: HashMap::new(),
+            base_positions,
+            autobuild: config.base.autobuild.then(AutobuildPolicy::default),
+            wear: WearConfig::default(),
+            build_orders: VecDeque::new(),
+            beacons: Vec::new(),
+            depots: Vec::new(),
+            comms_range: config.base.comms_range,
+            upkeep: config.base.upkeep,
+            energy_history: VecDeque::new(),
+            robot_stats: config.stats.clone(),
+            objectives: config.objectives.clone(),
+            outcome: None,
+            robots_destroyed: 0,
+            network: CommsNetwork::default(),
+            report_delays: (0, 0),
+            delivered: HashMap::new(),
+            first_delivery_tick: None,
+            robot_ticks: (0, 0),
+            events: EventBus::new(),
+        };
+        game.rebuild_network();
+        Ok(game)
     }
 
     pub fn get_map(&self) -> &[Vec<Tile>] {
         &self.map
     }
 
-    pub fn get_robots(&self) -> &[Robot] {
+    pub fn get_robots(&self) -> &RobotRegistry {
         &self.robots
     }
+
+    pub fn get_robot(&self, id: RobotId) -> Option<&Robot> {
+        self.robots.get(id)
+    }
+
+    /// Ajoute un robot en cours de partie et renvoie son identifiant.
+    pub fn spawn_robot(&mut self, x: usize, y: usize, robot_type: RobotType) -> RobotId {
+        let mut robot = Robot::new(x, y, robot_type);
+        robot.knowledge = KnowledgeMap::new(self.map[0].len(), self.map.len());
+        if let Some(&stats) = self.robot_stats.get(&robot_type) {
+            robot.set_base_stats(stats);
+        }
+        if let Some(upgrades) = self.type_upgrades.get(&robot_type) {
+            robot.upgrades = upgrades.clone();
+        }
+        self.robots.insert(robot)
+    }
+
+    /// Prix d'une amélioration pour la cible, sommé sur tous les robots concernés.
+    pub fn upgrade_cost(&self, target: UpgradeTarget, upgrade: Upgrade) -> Result<Vec<(Tile, u32)>, UpgradeError> {
+        let candidates = self.upgrade_candidates(target, upgrade)?;
+        if let (UpgradeTarget::Type(robot_type), true) = (target, candidates.is_empty()) {
+            // Aucun robot de ce type pour l'instant : on paie le prix d'un robot
+            let type_level = self.type_upgrades.get(&robot_type).map_or(0, |set| set.level(upgrade));
+            return Ok(upgrade.cost(type_level));
+        }
+
+        let mut total: BTreeMap<Tile, u32> = BTreeMap::new();
+        for robot_id in candidates {
+            let level = self.robots.get(robot_id).map_or(0, |robot| robot.upgrades.level(upgrade));
+            for (resource, amount) in upgrade.cost(level) {
+                *total.entry(resource).or_insert(0) += amount;
+            }
+        }
+        Ok(total.into_iter().collect())
+    }
+
+    /// Achète une amélioration avec le stock de la base et l'installe sur la cible.
+    /// Renvoie le nombre de robots améliorés.
+    pub fn purchase_upgrade(&mut self, target: UpgradeTarget, upgrade: Upgrade) -> Result<usize, UpgradeError> {
+        let candidates = self.upgrade_candidates(target, upgrade)?;
+        let cost = self.upgrade_cost(target, upgrade)?;
+        if !self.base.spend(&cost) {
+            return Err(UpgradeError::InsufficientResources);
+        }
+
+        for robot_id in &candidates {
+            if let Some(robot) = self.robots.get_mut(*robot_id) {
+                robot.upgrades.add(upgrade);
+            }
+        }
+        if let UpgradeTarget::Type(robot_type) = target {
+            self.type_upgrades.entry(robot_type).or_default().add(upgrade);
+        }
+        Ok(candidates.len())
+    }
+
+    /// Robots de la cible pouvant encore recevoir un niveau de l'amélioration.
+    fn upgrade_candidates(&self, target: UpgradeTarget, upgrade: Upgrade) -> Result<Vec<RobotId>, UpgradeError> {
+        match target {
+            UpgradeTarget::Robot(robot_id) => {
+                let robot = self.robots.get(robot_id).ok_or(UpgradeError::UnknownRobot)?;
+                if robot.upgrades.level(upgrade) >= MAX_UPGRADE_LEVEL {
+                    return Err(UpgradeError::MaxLevel);
+                }
+                Ok(vec![robot_id])
+            }
+            UpgradeTarget::Type(robot_type) => {
+                let type_level = self.type_upgrades.get(&robot_type).map_or(0, |set| set.level(upgrade));
+                if type_level >= MAX_UPGRADE_LEVEL {
+                    return Err(UpgradeError::MaxLevel);
+                }
+                Ok(self.robots.iter()
+                    .filter(|robot| robot.robot_type == robot_type)
+                    .filter(|robot| robot.upgrades.level(upgrade) < MAX_UPGRADE_LEVEL)
+                    .map(|robot| robot.id)
+                    .collect())
+            }
+        }
+    }
+
+    /// Commande la fabrication d'un robot avec le stock de la base.
+    pub fn queue_robot(&mut self, robot_type: RobotType) -> Result<(), FabricationError> {
+        self.base.queue_robot(robot_type)
+    }
+
+    pub fn get_production_queue(&self) -> &ProductionQueue {
+        &self.base.production
+    }
+
+    pub fn get_autobuild(&self) -> Option<&AutobuildPolicy> {
+        self.autobuild.as_ref()
+    }
+
+    /// Active (ou désactive avec `None`) la construction automatique.
+    pub fn set_autobuild(&mut self, policy: Option<AutobuildPolicy>) {
+        self.autobuild = policy;
+    }
+
+    /// Retire un robot de la partie ; la ressource qui lui était assignée redevient libre.
+    pub fn destroy_robot(&mut self, id: RobotId) -> Option<Robot> {
+        let robot = self.robots.remove(id)?;
+        self.release_assignment(id);
+        self.robots_destroyed += 1;
+        Some(robot)
+    }
+
+    /// Commande un ouvrage : le coût est prélevé tout de suite et un bâtisseur
+    /// libre sera envoyé sur le chantier.
+    pub fn queue_build(&mut self, structure: Structure, x: usize, y: usize) -> Result<(), BuildError> {
+        let order = BuildOrder::new(structure, x, y);
+        if y >= self.map.len() || x >= self.map[y].len() {
+            return Err(BuildError::OutOfBounds);
+        }
+        if !order.is_valid(&self.map) {
+            return Err(BuildError::InvalidGround);
+        }
+        if self.build_orders.iter().any(|other| other.x == x && other.y == y) {
+            return Err(BuildError::AlreadyPlanned);
+        }
+        if self.build_orders.len() >= MAX_BUILD_ORDERS {
+            return Err(BuildError::QueueFull);
+        }
+        if !self.base.spend(&structure.cost()) {
+            return Err(BuildError::InsufficientResources);
+        }
+        self.build_orders.push_back(order);
+        Ok(())
+    }
+
+    /// Commande une route le long du plus court chemin entre la base et (x, y),
+    /// tant que le stock le permet. Renvoie le nombre de tronçons commandés.
+    pub fn plan_road(&mut self, x: usize, y: usize) -> usize {
+        let path = shortest_path(&self.map, (self.base.x, self.base.y), (x, y));
+        let mut planned = 0;
+        for (road_x, road_y) in path {
+            match self.queue_build(Structure::Road, road_x, road_y) {
+                Ok(()) => planned += 1,
+                Err(BuildError::InvalidGround | BuildError::AlreadyPlanned) => {}
+                Err(_) => break,
+            }
+        }
+        planned
+    }
+
+    /// Route vers l'amas de ressources découvertes le plus dense.
+    pub fn plan_road_to_cluster(&mut self) -> usize {
+        let positions: Vec<(usize, usize)> = self.discovered_resources.iter().map(|res| (res.x, res.y)).collect();
+        match densest_cluster(&positions, CLUSTER_RADIUS) {
+            Some((x, y)) => self.plan_road(x, y),
+            None => 0,
+        }
+    }
+
+    /// Commande un dépôt près du plus gros amas de ressources éloigné de la base.
+    pub fn plan_depot(&mut self) -> Result<(usize, usize), BuildError> {
+        let resources: Vec<(usize, usize)> = self.discovered_resources.iter().map(|res| (res.x, res.y)).collect();
+        let depots: Vec<(usize, usize)> = self.depots.iter()
+            .map(|depot| (depot.x, depot.y))
+            .chain(self.build_orders.iter()
+                .filter(|order| order.structure == Structure::Depot)
+                .map(|order| (order.x, order.y)))
+            .collect();
+        let (x, y) = suggest_depot_site(&self.map, &resources, (self.base.x, self.base.y), &depots, CLUSTER_RADIUS)
+            .ok_or(BuildError::NoSuitableSite)?;
+        self.queue_build(Structure::Depot, x, y)?;
+        Ok((x, y))
+    }
+
+    /// Quais de déchargement sur le bord de la base.
+    pub fn get_docking_slots(&self) -> &[(usize, usize)] {
+        &self.base.docking_slots
+    }
+
+    pub fn get_depots(&self) -> &[Depot] {
+        &self.depots
+    }
+
+    pub fn get_build_orders(&self) -> &VecDeque<BuildOrder> {
+        &self.build_orders
+    }
+
+    pub fn get_comms_range(&self) -> usize {
+        self.comms_range
+    }
+
+    /// Portée radio de la base, mesurée depuis le bord de son emprise.
+    pub fn set_comms_range(&mut self, range: usize) {
+        self.comms_range = range;
+        self.rebuild_network();
+    }
+
+    pub fn get_comms_network(&self) -> &CommsNetwork {
+        &self.network
+    }
+
+    /// Délai moyen, en ticks, entre l'observation d'une ressource et son signalement à la base.
+    pub fn get_average_report_delay(&self) -> Option<f64> {
+        let (reports, total_delay) = self.report_delays;
+        (reports > 0).then(|| total_delay as f64 / reports as f64)
+    }
+
+    /// Ressources déchargées à la base depuis le début de la partie, par type.
+    pub fn get_delivered(&self) -> &HashMap<Tile, u32> {
+        &self.delivered
+    }
+
+    pub fn get_first_delivery_tick(&self) -> Option<u64> {
+        self.first_delivery_tick
+    }
+
+    pub fn get_objectives(&self) -> &ObjectiveSettings {
+        &self.objectives
+    }
+
+    /// Avancement de chaque objectif de la partie.
+    pub fn get_objective_progress(&self) -> Vec<ObjectiveProgress> {
+        Objective::list(&self.objectives).iter().map(|objective| objective.progress(self)).collect()
+    }
+
+    /// Victoire ou défaite, dès qu'elle est décidée ; la simulation peut continuer ensuite.
+    pub fn get_outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Tick auquel tous les objectifs ont été remplis, s'il y en a.
+    pub fn get_victory_tick(&self) -> Option<u64> {
+        self.outcome.filter(|outcome| outcome.outcome.is_victory()).map(|outcome| outcome.tick)
+    }
+
+    /// Robots détruits ou en panne.
+    pub fn get_robots_lost(&self) -> u32 {
+        self.robots_destroyed + self.robots.iter().filter(|robot| robot.is_broken()).count() as u32
+    }
+
+    /// Décide de la fin de partie à la fin d'un tick. La défaite par manque d'énergie
+    /// l'emporte sur une victoire obtenue au même tick, la victoire sur l'échéance.
+    fn check_outcome(&mut self) {
+        if self.outcome.is_some() || !self.objectives.is_set() {
+            return;
+        }
+        let elapsed = self.tick + 1;
+        let objectives = Objective::list(&self.objectives);
+        let energy = self.base.get_resources().get(&Tile::Energy).copied().unwrap_or(0);
+        let won = objectives.iter().any(Objective::is_goal)
+            && objectives.iter()
+                .filter(|objective| objective.is_goal())
+                .all(|objective| match *objective {
+                    Objective::Deliver { resource, amount } => {
+                        self.delivered.get(&resource).copied().unwrap_or(0) >= amount
+                    }
+                    Objective::Explore { percent } => self.get_coverage() * 100.0 >= percent,
+                    Objective::Survive { ticks } => elapsed >= ticks,
+                    _ => true,
+                });
+
+        let outcome = if self.objectives.keep_energy && energy == 0 {
+            Outcome::Defeat(DefeatReason::EnergyDepleted)
+        } else if won {
+            Outcome::Victory
+        } else if self.objectives.deadline > 0 && elapsed >= self.objectives.deadline {
+            Outcome::Defeat(DefeatReason::DeadlineMissed)
+        } else {
+            return;
+        };
+        let score = Score::new(outcome, elapsed, self.get_idle_ratio(), self.get_robots_lost());
+        self.outcome = Some(GameOutcome { tick: self.tick, outcome, score });
+        self.events.emit(GameEvent::GameOver { outcome });
+    }
+
+    /// Part du temps que les robots en état de marche ont passé à attendre sans rien faire, entre 0.0 et 1.0.
+    pub fn get_idle_ratio(&self) -> f64 {
+        let (idle, total) = self.robot_ticks;
+        if total == 0 { 0.0 } else { idle as f64 / total as f64 }
+    }
+
+    pub fn get_wear_config(&self) -> &WearConfig {
+        &self.wear
+    }
+
+    pub fn set_wear_config(&mut self, wear: WearConfig) {
+        self.wear = wear;
+    }
     
     pub fn get_base_resources(&self) -> &HashMap<Tile, u32> {
         self.base.get_resources()
     }
 
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn get_exploration_strategy(&self) -> ExplorationStrategy {
+        self.exploration_strategy
+    }
+
+    pub fn set_exploration_strategy(&mut self, strategy: ExplorationStrategy) {
+        self.exploration_strategy = strategy;
+        self.register_explorer_behavior();
+
+        for robot in self.robots.iter_mut().filter(|r| r.robot_type == RobotType::Explorer) {
+            robot.exploration_target = None;
+            robot.path.clear();
+        }
+    }
+
+    fn register_explorer_behavior(&mut self) {
+        let behavior: Box<dyn RobotBehavior> = match self.exploration_strategy {
+            ExplorationStrategy::RandomWalk => Box::new(RandomWalkExplorer),
+            ExplorationStrategy::Frontier => Box::new(FrontierExplorer),
+        };
+        self.behaviors.register(RobotType::Explorer, behavior);
+    }
+
+    /// Abonne un nouveau lecteur aux événements, distribués à la fin de chaque tick.
+    pub fn subscribe(&mut self) -> EventReceiver {
+        self.events.subscribe()
+    }
+
+    /// Reprend les abonnés d'une autre partie, par exemple celle remplacée par un chargement.
+    pub fn take_subscribers(&mut self, previous: &mut GameState) {
+        self.events = std::mem::take(&mut previous.events);
+    }
+
+    /// Réinstalle les comportements, qui ne font pas partie des sauvegardes.
+    /// Ceux remplacés par `register_behavior` reprennent leur valeur par défaut.
+    pub fn restore_behaviors(&mut self) {
+        self.behaviors = BehaviorRegistry::default();
+        self.register_explorer_behavior();
+    }
+
+    pub fn get_allocation_strategy(&self) -> AllocationStrategy {
+        self.allocation_strategy
+    }
+
+    pub fn set_allocation_strategy(&mut self, strategy: AllocationStrategy) {
+        self.allocation_strategy = strategy;
+    }
+
+    /// Coût des affectations pour les derniers ticks où il y en a eu.
+    pub fn get_allocation_history(&self) -> &VecDeque<AllocationMetrics> {
+        &self.allocation_history
+    }
+
+    /// Coût moyen (en cases de chemin) d'une affectation depuis le début de la partie.
+    pub fn get_average_assignment_cost(&self) -> Option<f64> {
+        let (assignments, total_cost) = self.allocation_totals;
+        (assignments > 0).then(|| total_cost as f64 / assignments as f64)
+    }
+
+    /// Remplace le comportement utilisé pour tous les robots d'un type.
+    pub fn register_behavior(&mut self, robot_type: RobotType, behavior: Box<dyn RobotBehavior>) {
+        self.behaviors.register(robot_type, behavior);
+    }
+
+    /// Cibles abandonnées récemment, de la plus ancienne à la plus récente.
+    pub fn get_diagnostics(&self) -> &VecDeque<Diagnostic> {
+        &self.diagnostics
+    }
+
+    /// Positions des ressources marquées inaccessibles depuis au moins une région.
+    pub fn get_unreachable_positions(&self) -> HashSet<(usize, usize)> {
+        self.unreachable_targets.iter().map(|&(_, x, y)| (x, y)).collect()
+    }
+
+    /// Cases connues de la base.
+    pub fn get_explored(&self) -> &[Vec<bool>] {
+        &self.explored
+    }
+
+    /// Proportion de la carte explorée, entre 0.0 et 1.0.
+    pub fn get_coverage(&self) -> f64 {
+        coverage(&self.explored)
+    }
+
+    /// Derniers relevés (tick, couverture), pris tous les `COVERAGE_SAMPLE_INTERVAL` ticks.
+    pub fn get_coverage_history(&self) -> &VecDeque<(u64, f64)> {
+        &self.coverage_history
+    }
+
     pub fn update(&mut self) {
-        self.update_explorers();
+        self.update_upkeep();
+        self.update_robots();
+        self.update_durability();
+        self.update_sensors();
         self.assign_resources_to_collectors();
-        self.update_collectors();
+        self.assign_build_orders();
+        self.update_production();
+        self.check_outcome();
+        self.events.flush(self.tick);
+
+        self.energy_history.push_back(self.base.get_resources().get(&Tile::Energy).copied().unwrap_or(0));
+        while self.energy_history.len() > ENERGY_FLOW_WINDOW {
+            self.energy_history.pop_front();
+        }
+
+        self.tick += 1;
+        if self.tick.is_multiple_of(COVERAGE_SAMPLE_INTERVAL) {
+            self.coverage_history.push_back((self.tick, self.get_coverage()));
+            if self.coverage_history.len() > MAX_COVERAGE_HISTORY {
+                self.coverage_history.pop_front();
+            }
+        }
     }
 
-    fn update_explorers(&mut self) {
-        let mut rng = rand::thread_rng();
-        let mut new_discoveries = Vec::new();
-        
-        let discovered_positions: std::collections::HashSet<(usize, usize)> = 
-            self.discovered_resources.iter().map(|res| (res.x, res.y)).collect();
-        
-        for robot in self.robots.iter_mut().filter(|r| r.robot_type == RobotType::Explorer) {
-            let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
-            let (dx, dy) = directions[rng.gen_range(0..directions.len())];
-            
-            let new_x = (robot.x as isize + dx) as usize;
-            let new_y = (robot.y as isize + dy) as usize;
-            
-            if robot.can_move_to(new_x, new_y, &self.map) {
-                robot.move_to(new_x, new_y);
-                
-                let current_tile = self.map[new_y][new_x];
-                if Self::is_resource_tile(current_tile) && !discovered_positions.contains(&(new_x, new_y)) {
-                    robot.record_exploration(new_x, new_y, current_tile);
-                    new_discoveries.push(DiscoveredResource {
-                        x: new_x,
-                        y: new_y,
-                        tile_type: current_tile,
-                        assigned_robot_id: None,
-                    });
+    /// Demande à chaque robot son action puis l'applique, un robot après l'autre
+    /// pour que chacun voie les décisions déjà prises ce tick.
+    fn update_robots(&mut self) {
+        for id in self.robots.ids() {
+            let Some(robot) = self.robots.get(id) else { continue };
+            let position_before = (robot.x, robot.y);
+            let action = match self.behaviors.get_mut(robot.robot_type) {
+                // Un robot en panne ne fait plus rien en attendant d'être remorqué
+                _ if robot.is_broken() => Action::Wait,
+                Some(behavior) => {
+                    let world = WorldView {
+                        map: &self.map,
+                        explored: robot.knowledge.explored(),
+                        robots: &self.robots,
+                        discovered_resources: &self.discovered_resources,
+                        base: (self.base.x, self.base.y),
+                        depots: &self.depots,
+                        docks: &self.base.docking_slots,
+                        regions: &self.regions,
+                        comms: self.network.coverage(),
+                        tick: self.tick,
+                    };
+                    behavior.tick(robot, &world)
                 }
+                None => Action::Wait,
+            };
+            // Un relais déployé attend par nature : il n'est pas compté au repos. Un robot
+            // en panne, déjà compté comme perdu s'il n'est pas sauvé, est laissé hors du relevé.
+            if let Some(robot) = self.robots.get(id).filter(|robot| !robot.is_broken()) {
+                let waiting = action == Action::Wait && !robot.is_deployed();
+                self.robot_ticks.0 += waiting as u64;
+                self.robot_ticks.1 += 1;
             }
+            self.apply_action(id, action);
+            self.wear_from_movement(id, position_before);
         }
-        
-        self.discovered_resources.extend(new_discoveries);
     }
 
-    fn update_collectors(&mut self) {
-        let assigned_resources: Vec<(usize, usize, usize)> = self.discovered_resources.iter()
-            .filter_map(|res| res.assigned_robot_id.map(|id| (id, res.x, res.y)))
-            .collect();
-        
-        let mut resources_to_remove = Vec::new();
-        
-        for (robot_id, robot) in self.robots.iter_mut().enumerate().filter(|(_, r)| r.robot_type != RobotType::Explorer) {
-            match robot.state {
-                RobotState::Idle => {
-                    // Robot en attente
-                },
-                RobotState::GoingToResource => {
-                    if let Some((_, target_x, target_y)) = assigned_resources.iter().find(|(id, _, _)| *id == robot_id) {
-                        let target_x = *target_x;
-                        let target_y = *target_y;
-                        
-                        if robot.x == target_x && robot.y == target_y {
-                            let tile = self.map[target_y][target_x];
-                            
-                            if robot.can_collect(tile) {
-                                robot.collect(tile);
-                                self.map[target_y][target_x] = Tile::Empty;
-                                resources_to_remove.push((target_x, target_y));
-                                robot.set_returning_to_base(self.base.x, self.base.y);
-                            } else {
-                            }
-                        } else {
-                            robot.move_toward(target_x, target_y, &self.map);
-                        }
+    /// Use le robot pour chaque case parcourue pendant son action.
+    fn wear_from_movement(&mut self, id: RobotId, position_before: (usize, usize)) {
+        let Some(robot) = self.robots.get_mut(id) else { return };
+        let distance = robot.x.abs_diff(position_before.0) + robot.y.abs_diff(position_before.1);
+        if distance > 0 {
+            robot.wear(self.wear.move_cost(&self.map, robot.x, robot.y) * distance as u32);
+        }
+    }
+
+    fn apply_action(&mut self, id: RobotId, action: Action) {
+        let rescue_position = match action {
+            Action::Rescue { target } => self.robots.get(target)
+                .filter(|other| other.is_broken())
+                .map(|other| (other.x, other.y)),
+            _ => None,
+        };
+        // Un seul robot à la fois décharge sur un quai de la base
+        let dock_taken = action == Action::Unload && self.robots.get(id).is_some_and(|robot| {
+            self.base.contains(robot.x, robot.y) && self.robots.iter().any(|other| {
+                other.id != id
+                    && (other.x, other.y) == (robot.x, robot.y)
+                    && matches!(other.state, RobotState::Unloading { .. })
+            })
+        });
+        let Some(robot) = self.robots.get_mut(id) else { return };
+
+        let is_move = matches!(
+            action,
+            Action::Wander | Action::Explore { .. } | Action::MoveToward { .. } | Action::Rescue { .. }
+        );
+        // Cases que le robot peut parcourir ce tick, selon la case d'où il part
+        let steps = if is_move { robot.move_steps(self.map[robot.y][robot.x].is_paved()) } else { 1 };
+        if steps == 0 {
+            return;
+        }
+
+        match action {
+            Action::Wait => {}
+            Action::Wander => {
+                let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+                let (dx, dy) = directions[self.rng.gen_range(0..directions.len())];
+                
+                let new_x = (robot.x as isize + dx) as usize;
+                let new_y = (robot.y as isize + dy) as usize;
+                
+                if robot.can_move_to(new_x, new_y, &self.map) {
+                    robot.move_to(new_x, new_y);
+                }
+            }
+            Action::Explore { x, y } => {
+                if robot.exploration_target != Some((x, y)) {
+                    robot.exploration_target = Some((x, y));
+                    robot.path.clear();
+                }
+                if robot.move_toward_by(x, y, &self.map, steps) != MoveOutcome::Moved {
+                    robot.exploration_target = None;
+                }
+            }
+            Action::MoveToward { x, y } => {
+                // Le chemin en cache peut mener vers une autre cible
+                if robot.path.last().is_some_and(|&end| end != (x, y)) {
+                    robot.path.clear();
+                }
+                let outcome = robot.move_toward_by(x, y, &self.map, steps);
+                let stalled = robot.stuck_counter > STALL_TIMEOUT;
+                let going_to_site = robot.state == RobotState::GoingToSite;
+
+                // Le robot remorqué suit son dépanneur
+                if let Some(towed_id) = robot.towing {
+                    let (robot_x, robot_y) = (robot.x, robot.y);
+                    if let Some(towed) = self.robots.get_mut(towed_id) {
+                        towed.move_to(robot_x, robot_y);
                     }
-                },
-                RobotState::ReturningToBase => {
-                    if robot.x == self.base.x && robot.y == self.base.y {
-                        let unloaded_items = robot.unload_inventory();
-                        for item in unloaded_items {
-                            self.base.add_resource(item);
+                }
+
+                if self.assignments.get(&id) == Some(&(x, y)) {
+                    if outcome == MoveOutcome::Unreachable {
+                        self.give_up_target(id, DiagnosticKind::Unreachable);
+                    } else if stalled {
+                        self.give_up_target(id, DiagnosticKind::Stalled);
+                    }
+                } else if going_to_site && (outcome == MoveOutcome::Unreachable || stalled) {
+                    self.cancel_build_order(id);
+                }
+            }
+            Action::Collect => {
+                let (x, y) = (robot.x, robot.y);
+                let tile = self.map[y][x];
+                if robot.can_collect(tile) {
+                    if !robot.advance_mining() {
+                        return;
+                    }
+                    robot.collect(tile);
+                    self.map[y][x] = Tile::Empty;
+                    self.events.emit(GameEvent::ResourcePickedUp { robot_id: id, x, y, resource: tile });
+                }
+                // Soute pleine : retour à la base. Sinon le robot redevient disponible
+                // et rentrera de lui-même si aucune autre ressource ne lui est confiée.
+                if robot.is_cargo_full() {
+                    robot.set_returning_to_base(self.base.x, self.base.y);
+                } else {
+                    robot.abandon_task();
+                }
+                self.cleanup_resource_at(x, y);
+            }
+            Action::Unload => {
+                if dock_taken {
+                    return;
+                }
+                if robot.advance_unloading() {
+                    let (x, y) = (robot.x, robot.y);
+                    let unloaded_items = robot.unload_inventory();
+                    let depot = self.depots.iter_mut().find(|depot| depot.x == x && depot.y == y);
+                    if !unloaded_items.is_empty() {
+                        self.events.emit(GameEvent::CargoDelivered {
+                            robot_id: id,
+                            x,
+                            y,
+                            resources: unloaded_items.clone(),
+                            depot: depot.is_some(),
+                        });
+                    }
+                    match depot {
+                        Some(depot) => unloaded_items.into_iter().for_each(|item| depot.deposit(item)),
+                        None => {
+                            if !unloaded_items.is_empty() {
+                                self.first_delivery_tick.get_or_insert(self.tick);
+                            }
+                            for item in unloaded_items {
+                                *self.delivered.entry(item).or_insert(0) += 1;
+                                self.base.add_resource(item);
+                            }
                         }
-                    } else {
-                        robot.move_toward(self.base.x, self.base.y, &self.map);
                     }
                 }
             }
+            Action::Deploy => {
+                // Un relais ne s'installe qu'à portée du réseau, pour le prolonger
+                if robot.robot_type == RobotType::Relay && self.network.is_covered(robot.x, robot.y) {
+                    robot.deploy();
+                }
+            }
+            Action::Load => {
+                let capacity = robot.effective_stats().cargo_capacity.saturating_sub(robot.inventory.len() as u32);
+                let Some(depot) = self.depots.iter_mut().find(|depot| depot.x == robot.x && depot.y == robot.y) else { return };
+                let loaded = depot.take(capacity);
+                if !loaded.is_empty() {
+                    robot.inventory.extend(loaded);
+                    robot.set_returning_to_base(self.base.x, self.base.y);
+                }
+            }
+            Action::Build => {
+                let Some(index) = self.build_orders.iter().position(|order| order.assigned_robot_id == Some(id)) else {
+                    robot.abandon_task();
+                    return;
+                };
+                let order = &self.build_orders[index];
+                if !order.is_valid(&self.map) {
+                    self.cancel_build_order(id);
+                    return;
+                }
+                if !robot.advance_building(order.structure.build_ticks()) {
+                    return;
+                }
+
+                robot.abandon_task();
+                let (x, y, structure) = (order.x, order.y, order.structure);
+                self.build_orders.remove(index);
+                self.map[y][x] = structure.tile();
+                match structure {
+                    Structure::Beacon => self.beacons.push((x, y)),
+                    Structure::Depot => self.depots.push(Depot::new(x, y)),
+                    Structure::Road | Structure::Bridge => {}
+                }
+                self.on_map_changed();
+            }
+            Action::Rescue { target } => {
+                let Some((x, y)) = rescue_position else {
+                    robot.rescue_target = None;
+                    return;
+                };
+                robot.rescue_target = Some(target);
+                if (robot.x, robot.y) != (x, y) && robot.move_toward_by(x, y, &self.map, steps) == MoveOutcome::Unreachable {
+                    robot.rescue_target = None;
+                }
+                if (robot.x, robot.y) == (x, y) {
+                    robot.rescue_target = None;
+                    robot.towing = Some(target);
+                    robot.path.clear();
+                }
+            }
         }
-        
-        for (x, y) in resources_to_remove {
-            self.cleanup_resource_at(x, y);
+    }
+
+    /// Annule le chantier confié au robot, rembourse la base et remet le robot au repos.
+    fn cancel_build_order(&mut self, id: RobotId) {
+        if let Some(index) = self.build_orders.iter().position(|order| order.assigned_robot_id == Some(id)) {
+            if let Some(order) = self.build_orders.remove(index) {
+                self.base.refund(&order.structure.cost());
+            }
+        }
+        if let Some(robot) = self.robots.get_mut(id) {
+            robot.abandon_task();
+        }
+    }
+
+    /// Les ponts peuvent relier des régions : on recalcule les régions et on
+    /// redonne leur chance aux cibles déclarées inaccessibles.
+    fn on_map_changed(&mut self) {
+        self.regions = label_regions(&self.map);
+        self.unreachable_targets.clear();
+    }
+
+    /// Confie chaque chantier libre, dans l'ordre des commandes, au bâtisseur libre
+    /// le plus proche d'une case d'où il peut construire.
+    fn assign_build_orders(&mut self) {
+        let builders: Vec<RobotId> = self.robots.iter()
+            .filter(|robot| robot.robot_type == RobotType::Builder && robot.is_idle())
+            .map(|robot| robot.id)
+            .collect();
+        if builders.is_empty() || self.build_orders.iter().all(|order| order.assigned_robot_id.is_some()) {
+            return;
+        }
+
+        let fields: Vec<Vec<Vec<Option<u32>>>> = builders.iter()
+            .filter_map(|&id| self.robots.get(id))
+            .map(|robot| distance_field(&self.map, (robot.x, robot.y)))
+            .collect();
+        let mut fields: Vec<Option<Vec<Vec<Option<u32>>>>> = fields.into_iter().map(Some).collect();
+
+        for order in self.build_orders.iter_mut().filter(|order| order.assigned_robot_id.is_none()) {
+            // Sur un obstacle, le bâtisseur construit depuis une case voisine
+            let approaches: Vec<(usize, usize)> = if self.map[order.y][order.x].is_walkable() {
+                vec![(order.x, order.y)]
+            } else {
+                [(0isize, 1isize), (1, 0), (0, -1), (-1, 0)].iter()
+                    .map(|(dx, dy)| ((order.x as isize + dx) as usize, (order.y as isize + dy) as usize))
+                    .filter(|&(x, y)| y < self.map.len() && x < self.map[y].len() && self.map[y][x].is_walkable())
+                    .collect()
+            };
+
+            let best = fields.iter()
+                .enumerate()
+                .filter_map(|(builder, field)| field.as_ref().map(|field| (builder, field)))
+                .flat_map(|(builder, field)| {
+                    approaches.iter().filter_map(move |&(x, y)| field[y][x].map(|cost| (cost, builder, (x, y))))
+                })
+                .min();
+
+            if let Some((_, builder, (x, y))) = best {
+                let robot_id = builders[builder];
+                order.assigned_robot_id = Some(robot_id);
+                if let Some(robot) = self.robots.get_mut(robot_id) {
+                    robot.set_build_site(x, y);
+                }
+                fields[builder] = None;
+            }
+        }
+    }
+
+    /// Vieillissement et pannes aléatoires, puis réparation des robots rentrés à la base.
+    fn update_durability(&mut self) {
+        let mut breakdowns = Vec::new();
+        for robot in self.robots.iter_mut() {
+            robot.age += 1;
+            if robot.is_broken() {
+                continue;
+            }
+            if self.wear.age_wear_interval > 0 && robot.age.is_multiple_of(self.wear.age_wear_interval) {
+                robot.wear(1);
+            }
+            let probability = self.wear.breakdown_probability(robot.hp, robot.stats.max_hp);
+            if robot.hp == 0 || self.rng.gen_bool(probability) {
+                breakdowns.push(robot.id);
+            }
+        }
+
+        for id in breakdowns {
+            self.break_down_robot(id);
         }
+        self.repair_robots_at_base();
     }
 
+    /// Immobilise le robot, libère sa ressource et dépose sa cargaison au sol.
+    fn break_down_robot(&mut self, id: RobotId) {
+        self.release_assignment(id);
+        let Some(robot) = self.robots.get_mut(id) else { return };
+        let (x, y) = (robot.x, robot.y);
+        let mut cargo = robot.break_down().into_iter();
+
+        // Une ressource par case vide, sous le robot puis autour de lui ; ce qui
+        // ne trouve pas de place reste à bord et sera livré après la réparation
+        let mut spilled = Vec::new();
+        let offsets = [(0, 0), (0, 1), (1, 0), (0, -1), (-1, 0), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+        for (dx, dy) in offsets {
+            let (nx, ny) = ((x as isize + dx) as usize, (y as isize + dy) as usize);
+            if ny >= self.map.len() || nx >= self.map[ny].len() || self.map[ny][nx] != Tile::Empty {
+                continue;
+            }
+            let Some(resource) = cargo.next() else { break };
+            self.map[ny][nx] = resource;
+            spilled.push(Sighting { x: nx, y: ny, tile: resource, tick: self.tick });
+        }
+        robot.inventory.extend(cargo);
+
+        // Ces cases sont déjà explorées : la panne signale directement la cargaison
+        // à la base, pour qu'un collecteur vienne la reprendre
+        self.report_sightings(spilled);
+    }
+
+    /// Un robot en panne ou endommagé posé sur la base est réparé si la base a
+    /// assez de Minéral. Le dépanneur est libéré dès que son robot atteint la base.
+    fn repair_robots_at_base(&mut self) {
+        let at_base: Vec<RobotId> = self.robots.iter()
+            .filter(|robot| robot.is_broken() || robot.is_damaged())
+            .filter(|robot| self.map[robot.y][robot.x] == Tile::Base)
+            .map(|robot| robot.id)
+            .collect();
+
+        let broken: HashSet<RobotId> = self.robots.iter()
+            .filter(|robot| robot.is_broken())
+            .map(|robot| robot.id)
+            .collect();
+        for robot in self.robots.iter_mut() {
+            if let Some(towed_id) = robot.towing {
+                if at_base.contains(&towed_id) || !broken.contains(&towed_id) {
+                    robot.towing = None;
+                }
+            }
+        }
+
+        for id in at_base {
+            let Some(robot) = self.robots.get_mut(id) else { continue };
+            if self.base.spend(&robot.repair_cost()) {
+                robot.repair();
+            }
+        }
+    }
+
+    /// Prélève l'entretien de la base et signale l'entrée ou la sortie du délestage.
+    fn update_upkeep(&mut self) {
+        let was_browned_out = self.base.is_browned_out();
+        let active_robots = self.robots.iter().filter(|robot| !robot.is_broken()).count();
+        self.base.pay_upkeep(self.upkeep.cost(active_robots));
+        let browned_out = self.base.is_browned_out();
+        if browned_out != was_browned_out {
+            self.events.emit(GameEvent::Brownout { active: browned_out });
+        }
+    }
+
+    /// Énergie consommée par la base au prochain tick, en unités.
+    pub fn get_upkeep_per_tick(&self) -> f64 {
+        let active_robots = self.robots.iter().filter(|robot| !robot.is_broken()).count();
+        self.upkeep.cost(active_robots) as f64 / MILLI_ENERGY as f64
+    }
+
+    /// Variation moyenne du stock d'énergie par tick sur les derniers ticks :
+    /// livraisons moins entretien et dépenses.
+    pub fn get_energy_flow(&self) -> f64 {
+        match (self.energy_history.front(), self.energy_history.back()) {
+            (Some(&first), Some(&last)) if self.energy_history.len() > 1 => {
+                (last as f64 - first as f64) / (self.energy_history.len() - 1) as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Faute d'énergie pour l'entretien, la fabrication s'arrête et les capteurs faiblissent.
+    pub fn is_browned_out(&self) -> bool {
+        self.base.is_browned_out()
+    }
+
+    /// Passe une commande automatique tant que la file n'est pas pleine, puis fait
+    /// avancer la fabrication ; un robot terminé apparaît sur une case de base libre.
+    fn update_production(&mut self) {
+        if self.base.is_browned_out() {
+            return;
+        }
+        if let Some(policy) = &self.autobuild {
+            if !self.base.production.is_full() {
+                let (robots, production) = (&self.robots, &self.base.production);
+                let next = policy.next_type(|robot_type| {
+                    robots.iter().filter(|robot| robot.robot_type == robot_type).count() + production.count(robot_type)
+                });
+                if let Some(robot_type) = next {
+                    // Faute de ressources, on réessaiera au prochain tick
+                    let _ = self.base.queue_robot(robot_type);
+                }
+            }
+        }
+
+        if let Some(robot_type) = self.base.production.advance() {
+            let (x, y) = self.spawn_point();
+            let robot_id = self.spawn_robot(x, y, robot_type);
+            self.events.emit(GameEvent::RobotBuilt { robot_id, robot_type });
+        }
+    }
+
+    /// Première case de base inoccupée, ou la première case de base si toutes le sont.
+    fn spawn_point(&self) -> (usize, usize) {
+        let occupied: HashSet<(usize, usize)> = self.robots.iter().map(|robot| (robot.x, robot.y)).collect();
+        self.base_positions.iter()
+            .find(|position| !occupied.contains(position))
+            .or(self.base_positions.first())
+            .copied()
+            .unwrap_or((self.base.x, self.base.y))
+    }
+
+    /// Libère la ressource assignée au robot, la marque inaccessible depuis sa région
+    /// et remet le robot au repos.
+    fn give_up_target(&mut self, id: RobotId, kind: DiagnosticKind) {
+        let Some((x, y)) = self.release_assignment(id) else { return };
+
+        if let Some(robot) = self.robots.get_mut(id) {
+            robot.abandon_task();
+            if let Some(region) = self.regions[robot.y][robot.x] {
+                self.unreachable_targets.insert((region, x, y));
+            }
+        }
+
+        self.events.emit(GameEvent::RobotStuck { robot_id: id, x, y, reason: kind });
+        self.diagnostics.push_back(Diagnostic { tick: self.tick, robot_id: id, x, y, kind });
+        if self.diagnostics.len() > MAX_DIAGNOSTICS {
+            self.diagnostics.pop_front();
+        }
+    }
+
+    /// Rend la ressource (ou le chantier) assignée au robot et renvoie la position
+    /// de la ressource.
+    fn release_assignment(&mut self, id: RobotId) -> Option<(usize, usize)> {
+        for order in self.build_orders.iter_mut().filter(|order| order.assigned_robot_id == Some(id)) {
+            order.assigned_robot_id = None;
+        }
+        let (x, y) = self.assignments.remove(&id)?;
+        for resource in self.discovered_resources.iter_mut().filter(|res| res.x == x && res.y == y) {
+            resource.assigned_robot_id = None;
+        }
+        Some((x, y))
+    }
+
+    fn is_marked_unreachable(&self, robot_id: RobotId, x: usize, y: usize) -> bool {
+        let Some(robot) = self.robots.get(robot_id) else { return true };
+        match self.regions[robot.y][robot.x] {
+            Some(region) => self.unreachable_targets.contains(&(region, x, y)),
+            None => false,
+        }
+    }
+
+    /// Chaque robot note dans sa propre carte les cases en ligne de vue dans son
+    /// rayon de capteur. La base n'apprend ces découvertes qu'à la synchronisation.
+    fn update_sensors(&mut self) {
+        self.rebuild_network();
+
+        // En délestage, la portée des capteurs est divisée par deux
+        let browned_out = self.base.is_browned_out();
+        let shrink = |radius: usize| if browned_out { (radius / 2).max(1) } else { radius };
+        for robot in self.robots.iter_mut().filter(|robot| !robot.is_broken()) {
+            let radius = shrink(robot.effective_stats().sensor_radius);
+            for (x, y) in visible_tiles(&self.map, (robot.x, robot.y), radius) {
+                let tile = self.map[y][x];
+                if robot.knowledge.observe(x, y, tile, self.tick) && Self::is_resource_tile(tile) {
+                    robot.record_exploration(x, y, tile);
+                }
+            }
+        }
+
+        self.sync_knowledge();
+
+        // Les balises reliées au réseau informent directement la base
+        let mut sightings = Vec::new();
+        for &(beacon_x, beacon_y) in self.beacons.iter().filter(|&&(x, y)| self.network.is_connected(x, y)) {
+            for (x, y) in visible_tiles(&self.map, (beacon_x, beacon_y), shrink(BEACON_SENSOR_RADIUS)) {
+                self.explored[y][x] = true;
+                let tile = self.map[y][x];
+                if Self::is_resource_tile(tile) {
+                    sightings.push(Sighting { x, y, tile, tick: self.tick });
+                }
+            }
+        }
+        self.report_sightings(sightings);
+    }
+
+    /// Recalcule la couverture radio : la base, puis les balises et relais déployés
+    /// qui lui sont reliés de proche en proche.
+    fn rebuild_network(&mut self) {
+        let half = self.base.size / 2;
+        let root = RadioNode::new(self.base.x + half, self.base.y + half, self.comms_range + half);
+        let beacons = self.beacons.iter().map(|&(x, y)| RadioNode::new(x, y, BEACON_COMMS_RANGE));
+        let relays = self.robots.iter()
+            .filter(|robot| robot.is_deployed())
+            .map(|robot| RadioNode::new(robot.x, robot.y, RELAY_COMMS_RANGE));
+        let nodes: Vec<RadioNode> = beacons.chain(relays).collect();
+        self.network = CommsNetwork::build(self.map[0].len(), self.map.len(), root, &nodes);
+    }
+
+    /// Les robots couverts par le réseau radio échangent leur carte avec la base
+    /// et lui signalent les ressources aperçues.
+    fn sync_knowledge(&mut self) {
+        let mut sightings = Vec::new();
+        for robot in self.robots.iter_mut() {
+            if self.network.is_covered(robot.x, robot.y) {
+                sightings.extend(robot.knowledge.sync_with(&mut self.explored));
+            }
+        }
+        self.report_sightings(sightings);
+    }
+
+    /// Ajoute aux ressources connues de la base celles qu'elle ignorait encore.
+    fn report_sightings(&mut self, sightings: Vec<Sighting>) {
+        let mut discovered_positions: HashSet<(usize, usize)> =
+            self.discovered_resources.iter().map(|res| (res.x, res.y)).collect();
+
+        for sighting in sightings {
+            if !discovered_positions.insert((sighting.x, sighting.y)) {
+                continue;
+            }
+            self.report_delays.0 += 1;
+            self.report_delays.1 += self.tick - sighting.tick;
+            self.discovered_resources.push(DiscoveredResource {
+                x: sighting.x,
+                y: sighting.y,
+                tile_type: sighting.tile,
+                assigned_robot_id: None,
+            });
+            self.events.emit(GameEvent::ResourceDiscovered { x: sighting.x, y: sighting.y, resource: sighting.tile });
+        }
+    }
+
+    /// Associe, pour chaque type de ressource, les robots libres aux ressources
+    /// non assignées selon la stratégie d'allocation et la longueur des chemins.
     fn assign_resources_to_collectors(&mut self) {
-        let mut available_robots = self.get_available_robots_by_type();
+        let available_robots = self.get_available_robots_by_type();
         
-        let mut assignments = Vec::new();
+        let mut new_assignments = Vec::new();
+        let mut metrics = AllocationMetrics::new(self.tick, self.allocation_strategy);
         
-        for (index, resource) in self.discovered_resources.iter().enumerate() {
-            if resource.assigned_robot_id.is_none() {
-                let robot_type = match resource.tile_type {
-                    Tile::Mineral => RobotType::Miner,
-                    Tile::Energy => RobotType::EnergyCollector,
-                    Tile::Science => RobotType::Scientist,
-                    _ => continue,
-                };
-                
-                if let Some(robot_list) = available_robots.get_mut(&robot_type) {
-                    if let Some(robot_id) = robot_list.pop() {
-                        assignments.push((index, robot_id, resource.x, resource.y));
-                    }
-                }
+        for (tile, robot_ids) in available_robots {
+            let tasks: Vec<usize> = self.discovered_resources.iter()
+                .enumerate()
+                .filter(|(_, res)| res.assigned_robot_id.is_none() && res.tile_type == tile)
+                .map(|(index, _)| index)
+                .collect();
+            if tasks.is_empty() {
+                continue;
+            }
+
+            let costs: Vec<Vec<Option<u32>>> = robot_ids.iter()
+                .map(|&robot_id| self.assignment_costs(robot_id, &tasks))
+                .collect();
+
+            for (robot, task) in allocate(self.allocation_strategy, &costs) {
+                let resource = &self.discovered_resources[tasks[task]];
+                new_assignments.push((tasks[task], robot_ids[robot], resource.x, resource.y));
+                metrics.record(costs[robot][task].unwrap_or(0));
             }
         }
         
-        for (resource_index, robot_id, target_x, target_y) in assignments {
+        for (resource_index, robot_id, target_x, target_y) in new_assignments {
             if let Some(resource) = self.discovered_resources.get_mut(resource_index) {
                 resource.assigned_robot_id = Some(robot_id);
+                self.events.emit(GameEvent::ResourceAssigned {
+                    robot_id,
+                    x: target_x,
+                    y: target_y,
+                    resource: resource.tile_type,
+                });
             }
             if let Some(robot) = self.robots.get_mut(robot_id) {
                 robot.set_target(target_x, target_y);
             }
+            self.assignments.insert(robot_id, (target_x, target_y));
+        }
+
+        if metrics.assignments > 0 {
+            self.allocation_totals.0 += metrics.assignments;
+            self.allocation_totals.1 += metrics.total_cost;
+            self.allocation_history.push_back(metrics);
+            if self.allocation_history.len() > MAX_ALLOCATION_HISTORY {
+                self.allocation_history.pop_front();
+            }
         }
     }
 
-    fn get_available_robots_by_type(&self) -> HashMap<RobotType, Vec<usize>> {
-        let mut available = HashMap::new();
-        available.insert(RobotType::Miner, Vec::new());
-        available.insert(RobotType::EnergyCollector, Vec::new());
-        available.insert(RobotType::Scientist, Vec::new());
+    /// Longueur du chemin entre le robot et chacune des ressources `tasks`,
+    /// `None` si elle est inaccessible ou déjà marquée comme telle.
+    fn assignment_costs(&self, robot_id: RobotId, tasks: &[usize]) -> Vec<Option<u32>> {
+        let Some(robot) = self.robots.get(robot_id) else { return vec![None; tasks.len()] };
+        let distances = distance_field(&self.map, (robot.x, robot.y));
+        tasks.iter()
+            .map(|&index| {
+                let resource = &self.discovered_resources[index];
+                if self.is_marked_unreachable(robot_id, resource.x, resource.y) {
+                    None
+                } else {
+                    distances[resource.y][resource.x]
+                }
+            })
+            .collect()
+    }
+
+    /// Robots libres regroupés par ressource qu'ils savent ramasser.
+    fn get_available_robots_by_type(&self) -> BTreeMap<Tile, Vec<RobotId>> {
+        let mut available: BTreeMap<Tile, Vec<RobotId>> = BTreeMap::new();
         
-        for (robot_id, robot) in self.robots.iter().enumerate() {
-            let already_assigned = self.discovered_resources.iter()
-                .any(|res| res.assigned_robot_id == Some(robot_id));
+        for robot in self.robots.iter() {
+            let already_assigned = self.assignments.contains_key(&robot.id);
             
-            if !already_assigned && robot.is_idle() && robot.robot_type != RobotType::Explorer {
-                if let Some(robot_list) = available.get_mut(&robot.robot_type) {
-                    robot_list.push(robot_id);
+            if let Some(resource) = robot.robot_type.collectable_resource() {
+                if !already_assigned && robot.is_idle() {
+                    available.entry(resource).or_default().push(robot.id);
                 }
             }
         }
@@ -197,6 +1254,11 @@ impl GameState {
     }
 
     fn cleanup_resource_at(&mut self, x: usize, y: usize) {
+        for resource in self.discovered_resources.iter().filter(|res| res.x == x && res.y == y) {
+            if let Some(robot_id) = resource.assigned_robot_id {
+                self.assignments.remove(&robot_id);
+            }
+        }
         self.discovered_resources.retain(|res| !(res.x == x && res.y == y));
     }
 
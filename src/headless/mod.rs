@@ -0,0 +1,5 @@
+pub mod summary;
+#[cfg(test)]
+mod tests;
+
+pub use summary::{Delivered, RunSummary, run_headless, run_headless_with};
\ No newline at end of file
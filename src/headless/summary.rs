@@ -0,0 +1,112 @@
+use std::fmt;
+use serde::Serialize;
+use crate::game::GameState;
+use crate::map::tile::Tile;
+use crate::objectives::{GameOutcome, ObjectiveProgress};
+
+/// Ressources déchargées à la base, par type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Delivered {
+    pub energy: u32,
+    pub mineral: u32,
+    pub science: u32,
+}
+
+/// Bilan d'une simulation, affiché en fin de partie sans interface.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunSummary {
+    pub ticks: u64,
+    pub delivered: Delivered,
+    pub idle_percent: f64,
+    pub tiles_explored: usize,
+    pub total_tiles: usize,
+    pub first_delivery_tick: Option<u64>,
+    pub objectives: Vec<ObjectiveProgress>,
+    pub outcome: Option<GameOutcome>,
+}
+
+impl RunSummary {
+    pub fn from_game(game: &GameState) -> Self {
+        let delivered = game.get_delivered();
+        let count = |tile: Tile| delivered.get(&tile).copied().unwrap_or(0);
+        let explored = game.get_explored();
+
+        Self {
+            ticks: game.get_tick(),
+            delivered: Delivered {
+                energy: count(Tile::Energy),
+                mineral: count(Tile::Mineral),
+                science: count(Tile::Science),
+            },
+            idle_percent: game.get_idle_ratio() * 100.0,
+            tiles_explored: explored.iter().flatten().filter(|&&seen| seen).count(),
+            total_tiles: explored.iter().map(|row| row.len()).sum(),
+            first_delivery_tick: game.get_first_delivery_tick(),
+            objectives: game.get_objective_progress(),
+            outcome: game.get_outcome().copied(),
+        }
+    }
+
+    /// Code de sortie du programme : 0 pour une victoire ou une partie sans objectif,
+    /// 1 pour une défaite, 3 si les objectifs n'étaient pas décidés à la fin de la simulation.
+    pub fn exit_code(&self) -> i32 {
+        match self.outcome {
+            Some(outcome) if outcome.outcome.is_victory() => 0,
+            Some(_) => 1,
+            None if self.objectives.is_empty() => 0,
+            None => 3,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("le bilan se sérialise toujours")
+    }
+}
+
+impl fmt::Display for RunSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Ticks simulés: {}", self.ticks)?;
+        writeln!(f, "Ressources livrées:")?;
+        writeln!(f, "  Énergie: {}", self.delivered.energy)?;
+        writeln!(f, "  Minéral: {}", self.delivered.mineral)?;
+        writeln!(f, "  Science: {}", self.delivered.science)?;
+        writeln!(f, "Robots au repos: {:.1}%", self.idle_percent)?;
+        writeln!(f, "Cases explorées: {}/{}", self.tiles_explored, self.total_tiles)?;
+        match self.first_delivery_tick {
+            Some(tick) => writeln!(f, "Première livraison: tick {}", tick)?,
+            None => writeln!(f, "Première livraison: aucune")?,
+        }
+        if self.objectives.is_empty() {
+            return write!(f, "Objectifs: aucun");
+        }
+        writeln!(f, "Objectifs:")?;
+        for progress in &self.objectives {
+            writeln!(f, "  {}", progress)?;
+        }
+        match self.outcome {
+            Some(outcome) => {
+                writeln!(f, "Issue: {} au tick {}", outcome.outcome, outcome.tick)?;
+                write!(f, "Score: {}", outcome.score)
+            }
+            None => write!(f, "Issue: partie non décidée"),
+        }
+    }
+}
+
+/// Fait tourner la simulation `ticks` fois sans aucun affichage et en dresse le bilan.
+/// La simulation s'arrête plus tôt si la partie est gagnée ou perdue.
+pub fn run_headless(game: &mut GameState, ticks: u64) -> RunSummary {
+    run_headless_with(game, ticks, |_| {})
+}
+
+/// Comme `run_headless`, en appelant `after_tick` après chaque mise à jour.
+pub fn run_headless_with(game: &mut GameState, ticks: u64, mut after_tick: impl FnMut(&GameState)) -> RunSummary {
+    for _ in 0..ticks {
+        if game.get_outcome().is_some() {
+            break;
+        }
+        game.update();
+        after_tick(game);
+    }
+    RunSummary::from_game(game)
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod headless_tests {
+    use super::super::run_headless;
+    use crate::config::WorldConfig;
+    use crate::game::GameState;
+
+    #[test]
+    fn test_headless_run_summary() {
+        let mut game = GameState::new(40, 40, 42);
+        let summary = run_headless(&mut game, 200);
+
+        assert_eq!(summary.ticks, 200);
+        assert_eq!(summary.total_tiles, 1600);
+        assert!(summary.tiles_explored > 0 && summary.tiles_explored <= summary.total_tiles);
+        assert!((0.0..=100.0).contains(&summary.idle_percent));
+        let delivered = summary.delivered.energy + summary.delivered.mineral + summary.delivered.science;
+        assert_eq!(summary.first_delivery_tick.is_some(), delivered > 0);
+
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+        assert_eq!(json["ticks"], 200);
+        assert_eq!(json["delivered"]["mineral"], summary.delivered.mineral);
+        assert!(summary.objectives.is_empty() && summary.outcome.is_none());
+        assert_eq!(summary.exit_code(), 0);
+    }
+
+    #[test]
+    fn test_headless_stops_when_decided() {
+        let mut config = WorldConfig { width: 30, height: 30, ..WorldConfig::default() };
+        config.objectives.survive_ticks = 20;
+        let summary = run_headless(&mut GameState::with_config(&config).unwrap(), 100);
+        assert_eq!(summary.ticks, 20);
+        assert_eq!(summary.exit_code(), 0);
+        assert!(summary.to_string().contains("Score: "));
+
+        config.objectives.survive_ticks = 0;
+        config.objectives.delivered.science = 1000;
+        let summary = run_headless(&mut GameState::with_config(&config).unwrap(), 20);
+        assert_eq!((summary.ticks, summary.exit_code()), (20, 3));
+
+        config.objectives.deadline = 10;
+        let summary = run_headless(&mut GameState::with_config(&config).unwrap(), 20);
+        assert_eq!((summary.ticks, summary.exit_code()), (10, 1));
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+
+/// Ressource aperçue par un robot et pas encore transmise à la base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sighting {
+    pub x: usize,
+    pub y: usize,
+    pub tile: Tile,
+    pub tick: u64,
+}
+
+/// Ce qu'un robot sait de la carte : les cases qu'il a vues lui-même ou
+/// apprises de la base, et les ressources qu'il doit encore signaler.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KnowledgeMap {
+    explored: Vec<Vec<bool>>,
+    pending: Vec<Sighting>,
+}
+
+impl KnowledgeMap {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { explored: vec![vec![false; width]; height], pending: Vec::new() }
+    }
+
+    pub fn explored(&self) -> &[Vec<bool>] {
+        &self.explored
+    }
+
+    pub fn is_explored(&self, x: usize, y: usize) -> bool {
+        self.explored.get(y).and_then(|row| row.get(x)).copied().unwrap_or(false)
+    }
+
+    /// Ressources aperçues depuis la dernière synchronisation, de la plus ancienne à la plus récente.
+    pub fn pending(&self) -> &[Sighting] {
+        &self.pending
+    }
+
+    /// Enregistre une case vue par le robot ; vrai si elle était encore inconnue.
+    /// Une ressource découverte ainsi est mise de côté pour la base.
+    pub fn observe(&mut self, x: usize, y: usize, tile: Tile, tick: u64) -> bool {
+        let Some(known) = self.explored.get_mut(y).and_then(|row| row.get_mut(x)) else { return false };
+        if *known {
+            return false;
+        }
+        *known = true;
+        if matches!(tile, Tile::Mineral | Tile::Energy | Tile::Science) {
+            self.pending.push(Sighting { x, y, tile, tick });
+        }
+        true
+    }
+
+    /// Échange les cases connues avec la carte maîtresse de la base (dans les deux sens)
+    /// et renvoie les ressources à signaler.
+    pub fn sync_with(&mut self, master: &mut [Vec<bool>]) -> Vec<Sighting> {
+        for (own_row, master_row) in self.explored.iter_mut().zip(master.iter_mut()) {
+            for (own, known) in own_row.iter_mut().zip(master_row.iter_mut()) {
+                *own |= *known;
+                *known = *own;
+            }
+        }
+        std::mem::take(&mut self.pending)
+    }
+}
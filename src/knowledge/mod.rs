@@ -0,0 +1,7 @@
+pub mod knowledge_map;
+pub mod network;
+#[cfg(test)]
+mod tests;
+
+pub use knowledge_map::{KnowledgeMap, Sighting};
+pub use network::{CommsNetwork, RadioNode};
\ No newline at end of file
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// Émetteur radio : la base, une balise ou un relais déployé.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RadioNode {
+    pub x: usize,
+    pub y: usize,
+    pub range: usize, // Portée en cases, diagonales comprises
+}
+
+impl RadioNode {
+    pub fn new(x: usize, y: usize, range: usize) -> Self {
+        Self { x, y, range }
+    }
+
+    /// Deux émetteurs communiquent si l'un des deux porte jusqu'à l'autre.
+    fn links_to(&self, other: &RadioNode) -> bool {
+        let distance = self.x.abs_diff(other.x).max(self.y.abs_diff(other.y));
+        distance <= self.range.max(other.range)
+    }
+}
+
+/// Zone couverte par les émetteurs reliés à la base, de proche en proche.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommsNetwork {
+    connected: Vec<RadioNode>,
+    coverage: Vec<Vec<bool>>,
+}
+
+impl CommsNetwork {
+    /// Réseau formé à partir de `root` (la base) et des émetteurs qu'il peut atteindre
+    /// par relais successifs ; les émetteurs isolés ne couvrent rien.
+    pub fn build(width: usize, height: usize, root: RadioNode, nodes: &[RadioNode]) -> Self {
+        let mut connected = vec![root];
+        let mut remaining: Vec<RadioNode> = nodes.to_vec();
+        let mut index = 0;
+        while index < connected.len() {
+            let node = connected[index];
+            let (linked, rest): (Vec<RadioNode>, Vec<RadioNode>) =
+                remaining.into_iter().partition(|other| node.links_to(other));
+            connected.extend(linked);
+            remaining = rest;
+            index += 1;
+        }
+
+        let mut coverage = vec![vec![false; width]; height];
+        for node in &connected {
+            let y_range = node.y.saturating_sub(node.range)..(node.y + node.range + 1).min(height);
+            for row in &mut coverage[y_range] {
+                let x_range = node.x.saturating_sub(node.range)..(node.x + node.range + 1).min(width);
+                row[x_range].fill(true);
+            }
+        }
+
+        Self { connected, coverage }
+    }
+
+    pub fn coverage(&self) -> &[Vec<bool>] {
+        &self.coverage
+    }
+
+    pub fn is_covered(&self, x: usize, y: usize) -> bool {
+        self.coverage.get(y).and_then(|row| row.get(x)).copied().unwrap_or(false)
+    }
+
+    /// Vrai si un émetteur situé en (x, y) est relié à la base.
+    pub fn is_connected(&self, x: usize, y: usize) -> bool {
+        self.connected.iter().any(|node| node.x == x && node.y == y)
+    }
+
+    /// Émetteurs reliés à la base, la base en premier.
+    pub fn connected(&self) -> &[RadioNode] {
+        &self.connected
+    }
+}
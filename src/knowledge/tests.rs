@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod knowledge_tests {
+    use super::super::{CommsNetwork, KnowledgeMap, RadioNode, Sighting};
+    use crate::map::tile::Tile;
+
+    #[test]
+    fn test_observe_records_new_resources_once() {
+        let mut knowledge = KnowledgeMap::new(3, 2);
+
+        assert!(knowledge.observe(1, 0, Tile::Mineral, 4));
+        assert!(!knowledge.observe(1, 0, Tile::Mineral, 5));
+        assert!(knowledge.observe(2, 1, Tile::Empty, 5));
+        assert!(!knowledge.observe(7, 7, Tile::Energy, 5));
+
+        assert!(knowledge.is_explored(1, 0));
+        assert_eq!(knowledge.pending(), &[Sighting { x: 1, y: 0, tile: Tile::Mineral, tick: 4 }]);
+    }
+
+    #[test]
+    fn test_sync_merges_both_ways() {
+        let mut knowledge = KnowledgeMap::new(3, 1);
+        knowledge.observe(0, 0, Tile::Energy, 1);
+        let mut master = vec![vec![false, false, true]];
+
+        let reported = knowledge.sync_with(&mut master);
+
+        assert_eq!(reported.len(), 1);
+        assert_eq!(master, vec![vec![true, false, true]]);
+        assert!(knowledge.is_explored(2, 0));
+        assert!(knowledge.pending().is_empty());
+    }
+
+    #[test]
+    fn test_network_chains_relays_from_root() {
+        let root = RadioNode::new(0, 0, 2);
+        let near = RadioNode::new(2, 0, 3);
+        let chained = RadioNode::new(5, 0, 1);
+        let isolated = RadioNode::new(9, 3, 1);
+
+        let network = CommsNetwork::build(12, 5, root, &[chained, isolated, near]);
+
+        assert_eq!(network.connected(), &[root, near, chained]);
+        assert!(network.is_connected(5, 0));
+        assert!(!network.is_connected(9, 3));
+        assert!(network.is_covered(6, 1));
+        assert!(!network.is_covered(7, 0));
+        assert!(!network.is_covered(9, 3));
+        assert!(!network.is_covered(20, 0));
+    }
+}
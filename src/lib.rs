@@ -0,0 +1,19 @@
+pub mod allocation;
+pub mod base;
+pub mod behavior;
+pub mod cli;
+pub mod clock;
+pub mod config;
+pub mod construction;
+pub mod events;
+pub mod exploration;
+pub mod game;
+pub mod headless;
+pub mod knowledge;
+pub mod map;
+pub mod metrics;
+pub mod objectives;
+pub mod replay;
+pub mod robot;
+pub mod save;
+pub mod ui;
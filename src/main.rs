@@ -1,38 +1,320 @@
-mod map;
-mod ui;
-mod robot;
-mod game;
-mod base;
-
-use std::io;
-use std::time::Duration;
+use std::{env, io, process};
+use std::time::{Duration, Instant};
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
-use game::GameState;
-use ui::terminal::AppUI;
+use astro_swarm::cli::{CliError, Command, USAGE, parse_args};
+use astro_swarm::config::Scenario;
+use astro_swarm::game::GameState;
+use astro_swarm::headless::run_headless_with;
+use astro_swarm::base::AutobuildPolicy;
+use astro_swarm::clock::SimClock;
+use astro_swarm::map::generate_map_with;
+use astro_swarm::metrics::Metrics;
+use astro_swarm::replay::{Input, Replay, ReplayPlayer, ReplayRecorder};
+use astro_swarm::robot::{RobotType, Upgrade};
+use astro_swarm::save::{self, QUICKSAVE_PATH};
+use astro_swarm::ui::terminal::{AppUI, SidebarPanel};
+
+// Cadence d'affichage, indépendante de celle de la simulation
+const FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
+// Déplacements dans une relecture : ←/→ et Page précédente/suivante, en ticks
+const SEEK_STEP: u64 = 100;
+const LONG_SEEK_STEP: u64 = 1000;
 
 fn main() -> Result<(), io::Error> {
-    let width = 80;
-    let height = 80;
-    let seed = 1337;
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = parse_args(&args).unwrap_or_else(|error| match error {
+        // L'aide n'éclaire pas une erreur dans le fichier de scénario
+        CliError::Config(_) => exit_with_error(error),
+        _ => {
+            eprintln!("erreur : {}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    });
+
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Run { scenario, load, record, metrics: metrics_path } => {
+            let mut game = start_game(&scenario, load.as_deref());
+            let events = game.subscribe();
+            // L'historique complet n'est gardé que pour l'export
+            let mut metrics = match metrics_path {
+                Some(_) => Metrics::with_history(&game, events),
+                None => Metrics::new(&game, events),
+            };
+            let recorder = record.as_ref().map(|_| ReplayRecorder::new(&scenario));
+            let replay = run_interactive(game, SimClock::new(scenario.tick_rate), recorder, &mut metrics)?;
+            if let (Some(replay), Some(path)) = (replay, record) {
+                replay.save(&path).unwrap_or_else(|error| exit_with_error(error));
+                eprintln!("Partie enregistrée dans {} ({} ticks)", path, replay.end_tick);
+            }
+            if let Some(path) = metrics_path {
+                export_metrics(&metrics, &path);
+            }
+        }
+        Command::Headless { scenario, ticks, json, load, metrics: metrics_path } => {
+            let mut game = start_game(&scenario, load.as_deref());
+            // Relevés pris seulement s'ils sont exportés
+            let mut metrics = metrics_path.as_ref().map(|_| {
+                let events = game.subscribe();
+                Metrics::with_history(&game, events)
+            });
+            let summary = run_headless_with(&mut game, ticks, |game| {
+                if let Some(metrics) = &mut metrics {
+                    metrics.sample(game);
+                }
+            });
+            if let (Some(metrics), Some(path)) = (&metrics, metrics_path) {
+                export_metrics(metrics, &path);
+            }
+            if json {
+                println!("{}", summary.to_json());
+            } else {
+                println!("{}", summary);
+            }
+            process::exit(summary.exit_code());
+        }
+        Command::GenMap(scenario) => {
+            if let Err(error) = scenario.validate() {
+                exit_with_error(error);
+            }
+            let world = &scenario.world;
+            let map = generate_map_with(&world.terrain, world.width, world.height, world.seed);
+            for row in map {
+                println!("{}", row.into_iter().map(|tile| tile.to_char()).collect::<String>());
+            }
+        }
+        Command::DumpConfig(scenario) => {
+            if let Err(error) = scenario.validate() {
+                exit_with_error(error);
+            }
+            print!("{}", scenario.to_toml());
+        }
+        Command::Replay { path } => {
+            let player = Replay::load(&path)
+                .and_then(ReplayPlayer::new)
+                .unwrap_or_else(|error| exit_with_error(error));
+            run_replay(player)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Partie reprise depuis une sauvegarde, ou créée à partir du scénario.
+fn start_game(scenario: &Scenario, load: Option<&str>) -> GameState {
+    match load {
+        Some(path) => save::load(path).unwrap_or_else(|error| exit_with_error(error)),
+        None => create_game(scenario),
+    }
+}
+
+fn create_game(scenario: &Scenario) -> GameState {
+    if let Err(error) = scenario.validate() {
+        exit_with_error(error);
+    }
+    GameState::with_config(&scenario.world).unwrap_or_else(|error| exit_with_error(error))
+}
+
+fn exit_with_error(error: impl std::fmt::Display) -> ! {
+    eprintln!("erreur : {}", error);
+    process::exit(2);
+}
+
+fn export_metrics(metrics: &Metrics, path: &str) {
+    if let Err(error) = metrics.save_csv(path) {
+        exit_with_error(format!("écriture de {} impossible : {}", path, error));
+    }
+    eprintln!("Relevés exportés dans {} ({} ticks)", path, metrics.history().len());
+}
+
+/// Applique une commande du joueur, en la notant si la partie est enregistrée.
+fn apply_input(game: &mut GameState, recorder: &mut Option<ReplayRecorder>, input: Input) {
+    match recorder {
+        Some(recorder) => recorder.apply(game, input),
+        None => input.apply(game),
+    }
+}
 
-    let mut game_state = GameState::new(width, height, seed);
+/// Partie dans le terminal ; renvoie l'enregistrement si `recorder` est fourni.
+/// `metrics` doit être abonné à `game_state` et reçoit un relevé par tick.
+fn run_interactive(
+    mut game_state: GameState,
+    mut clock: SimClock,
+    mut recorder: Option<ReplayRecorder>,
+    metrics: &mut Metrics,
+) -> io::Result<Option<Replay>> {
     let mut app_ui = AppUI::new()?;
+    app_ui.event_log.attach(game_state.subscribe());
+    let mut last_frame = Instant::now();
 
     loop {
-        game_state.update();
-        app_ui.render(game_state.get_map(), game_state.get_robots(), game_state.get_base_resources())?;
+        let frame_start = Instant::now();
+        let decided = game_state.get_outcome().is_some();
+        for _ in 0..clock.advance(frame_start - last_frame) {
+            game_state.update();
+            metrics.sample(&game_state);
+            if let Some(recorder) = &mut recorder {
+                recorder.after_tick(&game_state);
+            }
+        }
+        last_frame = frame_start;
+        // La partie continue en pause derrière l'écran de fin, pour qui veut la prolonger
+        if !decided && game_state.get_outcome().is_some() {
+            app_ui.outcome_screen.open = true;
+            if !clock.is_paused() {
+                clock.toggle_pause();
+            }
+        }
+        app_ui.render(&game_state, &clock, metrics)?;
+
+        // Les touches sont lues jusqu'à l'image suivante
+        if event::poll(FRAME_INTERVAL.saturating_sub(frame_start.elapsed()))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && app_ui.outcome_screen.open {
+                    match key.code {
+                        KeyCode::Char('q') => break,
+                        KeyCode::Enter | KeyCode::Esc => app_ui.outcome_screen.open = false,
+                        _ => {}
+                    }
+                } else if key.kind == KeyEventKind::Press && app_ui.upgrade_panel.open {
+                    match key.code {
+                        KeyCode::Char('u') | KeyCode::Esc => app_ui.upgrade_panel.toggle(),
+                        KeyCode::Left => app_ui.upgrade_panel.previous_target(&game_state),
+                        KeyCode::Right => app_ui.upgrade_panel.next_target(&game_state),
+                        KeyCode::Char(c @ '1'..='4') => {
+                            let upgrade = Upgrade::ALL[c as usize - '1' as usize];
+                            if let (Some(target), Some(recorder)) =
+                                (app_ui.upgrade_panel.purchase(&mut game_state, upgrade), &mut recorder)
+                            {
+                                recorder.record(game_state.get_tick(), Input::PurchaseUpgrade { target, upgrade });
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => break,
+                        KeyCode::Char(' ') => clock.toggle_pause(),
+                        KeyCode::Char('.') => clock.step(),
+                        KeyCode::Char('+') | KeyCode::Char('=') => clock.faster(),
+                        KeyCode::Char('-') => clock.slower(),
+                        KeyCode::Char('x') => {
+                            let strategy = game_state.get_exploration_strategy().toggled();
+                            apply_input(&mut game_state, &mut recorder, Input::SetExplorationStrategy(strategy));
+                        }
+                        KeyCode::Char('a') => {
+                            let strategy = game_state.get_allocation_strategy().next();
+                            apply_input(&mut game_state, &mut recorder, Input::SetAllocationStrategy(strategy));
+                        }
+                        KeyCode::Char('u') => app_ui.upgrade_panel.toggle(),
+                        KeyCode::Char('n') => app_ui.show_network = !app_ui.show_network,
+                        KeyCode::Char('e') => app_ui.toggle_panel(SidebarPanel::Events),
+                        KeyCode::Char('m') => app_ui.toggle_panel(SidebarPanel::Metrics),
+                        KeyCode::Char('s') => {
+                            app_ui.status = Some(match save::save(&game_state, QUICKSAVE_PATH) {
+                                Ok(()) => format!("Partie sauvegardée (tick {})", game_state.get_tick()),
+                                Err(error) => format!("Échec de la sauvegarde : {}", error),
+                            });
+                        }
+                        KeyCode::Char('l') if recorder.is_some() => {
+                            app_ui.status = Some("Chargement impossible pendant un enregistrement".to_string());
+                        }
+                        KeyCode::Char('l') => {
+                            app_ui.status = Some(match save::load(QUICKSAVE_PATH) {
+                                Ok(mut loaded) => {
+                                    loaded.take_subscribers(&mut game_state);
+                                    game_state = loaded;
+                                    metrics.resync(&game_state);
+                                    format!("Partie chargée (tick {})", game_state.get_tick())
+                                }
+                                Err(error) => format!("Échec du chargement : {}", error),
+                            });
+                        }
+                        KeyCode::Char(c @ '1'..='8') => {
+                            // Sans ressources ou file pleine, la commande est simplement ignorée
+                            let robot_type = RobotType::ALL[c as usize - '1' as usize];
+                            apply_input(&mut game_state, &mut recorder, Input::QueueRobot(robot_type));
+                        }
+                        KeyCode::Char('c') => apply_input(&mut game_state, &mut recorder, Input::PlanRoad),
+                        // Sans emplacement ou sans Minéral, rien n'est commandé
+                        KeyCode::Char('d') => apply_input(&mut game_state, &mut recorder, Input::PlanDepot),
+                        KeyCode::Char('b') => {
+                            let policy = match game_state.get_autobuild() {
+                                Some(_) => None,
+                                None => Some(AutobuildPolicy::default()),
+                            };
+                            apply_input(&mut game_state, &mut recorder, Input::SetAutobuild(policy));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(recorder.map(ReplayRecorder::finish))
+}
 
-        if event::poll(Duration::from_millis(100))? {
+/// Relecture d'une partie enregistrée, avec retour en arrière et avance rapide.
+fn run_replay(mut player: ReplayPlayer) -> io::Result<()> {
+    let mut app_ui = AppUI::new()?;
+    app_ui.event_log.attach(player.subscribe());
+    let events = player.subscribe();
+    let mut metrics = Metrics::new(player.game(), events);
+    let mut clock = SimClock::new(player.tick_rate());
+    let mut last_frame = Instant::now();
+
+    loop {
+        let frame_start = Instant::now();
+        for _ in 0..clock.advance(frame_start - last_frame) {
+            player.step();
+            metrics.sample(player.game());
+        }
+        if player.is_finished() && !clock.is_paused() {
+            clock.toggle_pause();
+        }
+        last_frame = frame_start;
+        app_ui.status = Some(match player.divergence() {
+            Some(tick) => format!("Relecture {}/{} - divergence au tick {}", player.tick(), player.end_tick(), tick),
+            None => format!("Relecture {}/{}", player.tick(), player.end_tick()),
+        });
+        app_ui.render(player.game(), &clock, &metrics)?;
+
+        if event::poll(FRAME_INTERVAL.saturating_sub(frame_start.elapsed()))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
                     match key.code {
                         KeyCode::Char('q') | KeyCode::Esc => break,
+                        KeyCode::Char(' ') => clock.toggle_pause(),
+                        KeyCode::Char('.') => clock.step(),
+                        KeyCode::Char('+') | KeyCode::Char('=') => clock.faster(),
+                        KeyCode::Char('-') => clock.slower(),
+                        KeyCode::Char('n') => app_ui.show_network = !app_ui.show_network,
+                        KeyCode::Char('e') => app_ui.toggle_panel(SidebarPanel::Events),
+                        KeyCode::Char('m') => app_ui.toggle_panel(SidebarPanel::Metrics),
                         _ => {}
                     }
+                    let target = match key.code {
+                        KeyCode::Left => Some(player.tick().saturating_sub(SEEK_STEP)),
+                        KeyCode::Right => Some(player.tick() + SEEK_STEP),
+                        KeyCode::PageUp => Some(player.tick().saturating_sub(LONG_SEEK_STEP)),
+                        KeyCode::PageDown => Some(player.tick() + LONG_SEEK_STEP),
+                        KeyCode::Home => Some(0),
+                        KeyCode::End => Some(player.end_tick()),
+                        _ => None,
+                    };
+                    if let Some(target) = target {
+                        // Le journal et les relevés repartent de zéro pour ne pas mêler l'avant et l'après du saut
+                        app_ui.event_log.clear();
+                        player.seek(target);
+                        let events = player.subscribe();
+                        metrics = Metrics::new(player.game(), events);
+                    }
                 }
             }
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,29 @@
+use std::collections::VecDeque;
+use super::tile::Tile;
+
+/// Longueur du plus court chemin (voisinage 4) depuis `start` vers chaque case,
+/// `None` pour les cases inaccessibles.
+pub fn distance_field(map: &[Vec<Tile>], start: (usize, usize)) -> Vec<Vec<Option<u32>>> {
+    let mut distances: Vec<Vec<Option<u32>>> = map.iter().map(|row| vec![None; row.len()]).collect();
+    let mut queue = VecDeque::new();
+
+    distances[start.1][start.0] = Some(0);
+    queue.push_back(start);
+
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    while let Some((x, y)) = queue.pop_front() {
+        let distance = distances[y][x].unwrap_or(0);
+        for (dx, dy) in directions {
+            let new_x = (x as isize + dx) as usize;
+            let new_y = (y as isize + dy) as usize;
+            if new_y < map.len() && new_x < map[new_y].len()
+                && distances[new_y][new_x].is_none() && map[new_y][new_x].is_walkable() {
+                distances[new_y][new_x] = Some(distance + 1);
+                queue.push_back((new_x, new_y));
+            }
+        }
+    }
+
+    distances
+}
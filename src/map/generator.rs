@@ -1,14 +1,91 @@
 use noise::{NoiseFn, Perlin};
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
 
 use super::tile::Tile;
 
+/// Famille de cartes produites à partir du bruit de Perlin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MapGenerator {
+    /// Terrain d'origine : quelques massifs, ressources en bordure
+    #[default]
+    Perlin,
+    /// Réseau de galeries étroites entre de larges obstacles
+    Caves,
+    /// Plaine sans obstacle, ressources réparties par plaques
+    Open,
+}
+
+impl MapGenerator {
+    pub const ALL: [MapGenerator; 3] = [MapGenerator::Perlin, MapGenerator::Caves, MapGenerator::Open];
+
+    /// Nom utilisé en ligne de commande.
+    pub fn key(&self) -> &'static str {
+        match self {
+            MapGenerator::Perlin => "perlin",
+            MapGenerator::Caves => "caves",
+            MapGenerator::Open => "open",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|generator| generator.key() == key)
+    }
+
+    /// Paramètres de terrain propres à cette famille de cartes.
+    pub fn params(&self) -> TerrainParams {
+        let (obstacle_threshold, resource_threshold) = match self {
+            MapGenerator::Perlin => (0.45, 0.2),
+            MapGenerator::Caves => (0.1, -0.15),
+            // Le bruit ne dépasse jamais 1 : aucun obstacle
+            MapGenerator::Open => (1.0, 0.2),
+        };
+        TerrainParams { obstacle_threshold, resource_threshold, ..TerrainParams::default() }
+    }
+}
+
+/// Réglages fins du générateur de carte.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TerrainParams {
+    /// Pas d'échantillonnage du bruit : plus il est petit, plus les massifs sont larges
+    pub scale: f64,
+    /// Au-dessus de ce niveau de bruit, la case est un obstacle
+    pub obstacle_threshold: f64,
+    /// Entre ce niveau et le précédent, la case peut porter une ressource
+    pub resource_threshold: f64,
+    /// Chances (en %) qu'une case de la zone de ressources porte chaque ressource
+    pub energy_percent: u32,
+    pub mineral_percent: u32,
+    pub science_percent: u32,
+}
+
+impl Default for TerrainParams {
+    fn default() -> Self {
+        Self {
+            scale: 0.1,
+            obstacle_threshold: 0.45,
+            resource_threshold: 0.2,
+            energy_percent: 6,
+            mineral_percent: 6,
+            science_percent: 3,
+        }
+    }
+}
+
 pub fn generate_map(width: usize, height: usize, seed: u32) -> Vec<Vec<Tile>> {
+    generate_map_with(&MapGenerator::Perlin.params(), width, height, seed)
+}
+
+pub fn generate_map_with(params: &TerrainParams, width: usize, height: usize, seed: u32) -> Vec<Vec<Tile>> {
     let perlin = Perlin::new(seed);
     let mut rng = StdRng::seed_from_u64(seed as u64);
+    let energy_limit = params.energy_percent;
+    let mineral_limit = energy_limit + params.mineral_percent;
+    let science_limit = mineral_limit + params.science_percent;
 
-    let scale = 0.1;
+    let scale = params.scale;
     let mut map = vec![vec![Tile::Empty; width]; height];
 
     let base_size = usize::min(10, usize::min(width, height) / 5);
@@ -27,12 +104,12 @@ pub fn generate_map(width: usize, height: usize, seed: u32) -> Vec<Vec<Tile>> {
             let noise_val = perlin.get([nx, ny]);
 
             map[y][x] = match noise_val {
-                v if v > 0.45 => Tile::Obstacle,
-                v if v > 0.2 => {
+                v if v > params.obstacle_threshold => Tile::Obstacle,
+                v if v > params.resource_threshold => {
                     match rng.gen_range(0..100) {
-                        0..=5 => Tile::Energy,
-                        6..=11 => Tile::Mineral,
-                        12..=14 => Tile::Science,
+                        roll if roll < energy_limit => Tile::Energy,
+                        roll if roll < mineral_limit => Tile::Mineral,
+                        roll if roll < science_limit => Tile::Science,
                         _ => Tile::Empty,
                     }
                 }
@@ -42,4 +119,4 @@ pub fn generate_map(width: usize, height: usize, seed: u32) -> Vec<Vec<Tile>> {
     }
 
     map
-}
\ No newline at end of file
+}
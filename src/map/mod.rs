@@ -1,6 +1,12 @@
+pub mod distance;
 pub mod generator;
+pub mod regions;
 pub mod tile;
+pub mod visibility;
 #[cfg(test)]
 mod tests;
 
-pub use generator::generate_map;
+pub use distance::distance_field;
+pub use generator::{MapGenerator, TerrainParams, generate_map, generate_map_with};
+pub use regions::label_regions;
+pub use visibility::visible_tiles;
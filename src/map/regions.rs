@@ -0,0 +1,37 @@
+use std::collections::VecDeque;
+use super::tile::Tile;
+
+/// Numérote les zones praticables connexes (voisinage 4) de la carte.
+/// Les cases infranchissables n'appartiennent à aucune région.
+pub fn label_regions(map: &[Vec<Tile>]) -> Vec<Vec<Option<u32>>> {
+    let mut regions: Vec<Vec<Option<u32>>> = map.iter().map(|row| vec![None; row.len()]).collect();
+    let mut next_region = 0;
+
+    for y in 0..map.len() {
+        for x in 0..map[y].len() {
+            if regions[y][x].is_some() || !map[y][x].is_walkable() {
+                continue;
+            }
+
+            let mut queue = VecDeque::new();
+            regions[y][x] = Some(next_region);
+            queue.push_back((x, y));
+
+            while let Some((cx, cy)) = queue.pop_front() {
+                let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+                for (dx, dy) in directions {
+                    let nx = (cx as isize + dx) as usize;
+                    let ny = (cy as isize + dy) as usize;
+                    if ny < map.len() && nx < map[ny].len()
+                        && regions[ny][nx].is_none() && map[ny][nx].is_walkable() {
+                        regions[ny][nx] = Some(next_region);
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+            next_region += 1;
+        }
+    }
+
+    regions
+}
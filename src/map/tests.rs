@@ -51,4 +51,99 @@ mod tile_tests {
         assert_eq!(Tile::Base.to_char(), 'B');
         assert_eq!(Tile::Robot.to_char(), 'R');
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod visibility_tests {
+    use super::super::visibility::{bresenham_line, has_line_of_sight, visible_tiles};
+    use super::super::tile::Tile;
+
+    #[test]
+    fn test_bresenham_line() {
+        assert_eq!(bresenham_line((0, 0), (3, 0)), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(bresenham_line((0, 0), (2, 2)), vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(bresenham_line((2, 1), (0, 1)), vec![(2, 1), (1, 1), (0, 1)]);
+    }
+
+    #[test]
+    fn test_obstacle_blocks_sight() {
+        let map = vec![vec![Tile::Empty, Tile::Obstacle, Tile::Empty, Tile::Empty]];
+
+        assert!(has_line_of_sight(&map, (0, 0), (1, 0)));
+        assert!(!has_line_of_sight(&map, (0, 0), (2, 0)));
+    }
+
+    #[test]
+    fn test_visible_tiles_radius() {
+        let map = vec![vec![Tile::Empty; 7]; 7];
+        let visible = visible_tiles(&map, (3, 3), 2);
+
+        assert!(visible.contains(&(3, 3)));
+        assert!(visible.contains(&(5, 3)));
+        assert!(visible.contains(&(4, 4)));
+        assert!(!visible.contains(&(5, 5)));
+        assert!(!visible.contains(&(6, 3)));
+    }
+
+    #[test]
+    fn test_visible_tiles_hidden_behind_obstacle() {
+        let map = vec![vec![Tile::Empty, Tile::Empty, Tile::Obstacle, Tile::Mineral]];
+        let visible = visible_tiles(&map, (0, 0), 5);
+
+        assert!(visible.contains(&(2, 0)));
+        assert!(!visible.contains(&(3, 0)));
+    }
+
+    #[test]
+    fn test_visible_tiles_empty_map() {
+        assert!(visible_tiles(&[], (0, 0), 3).is_empty());
+        assert!(visible_tiles(&[Vec::new()], (0, 0), 3).is_empty());
+    }
+
+    #[test]
+    fn test_visible_tiles_huge_radius() {
+        let map = vec![vec![Tile::Empty; 3]; 3];
+        assert_eq!(visible_tiles(&map, (1, 1), usize::MAX).len(), 9);
+    }
+}
+
+#[cfg(test)]
+mod regions_tests {
+    use super::super::regions::label_regions;
+    use super::super::tile::Tile;
+
+    #[test]
+    fn test_label_regions() {
+        let map = vec![
+            vec![Tile::Empty, Tile::Obstacle, Tile::Mineral],
+            vec![Tile::Base, Tile::Obstacle, Tile::Empty],
+        ];
+        let regions = label_regions(&map);
+
+        assert_eq!(regions[0][0], regions[1][0]);
+        assert_eq!(regions[0][2], regions[1][2]);
+        assert_ne!(regions[0][0], regions[0][2]);
+        assert_eq!(regions[0][1], None);
+    }
+}
+
+#[cfg(test)]
+mod distance_tests {
+    use super::super::distance::distance_field;
+    use super::super::tile::Tile;
+
+    #[test]
+    fn test_distance_field() {
+        let map = vec![
+            vec![Tile::Empty, Tile::Obstacle, Tile::Empty],
+            vec![Tile::Empty, Tile::Empty, Tile::Empty],
+            vec![Tile::Obstacle, Tile::Obstacle, Tile::Obstacle],
+        ];
+        let distances = distance_field(&map, (0, 0));
+
+        assert_eq!(distances[0][0], Some(0));
+        assert_eq!(distances[1][2], Some(3));
+        assert_eq!(distances[0][2], Some(4));
+        assert_eq!(distances[0][1], None);
+    }
+}
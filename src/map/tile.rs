@@ -1,4 +1,6 @@
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Tile {
     Empty,
     Obstacle,
@@ -7,6 +9,14 @@ pub enum Tile {
     Science,
     Base,
     Robot,
+    /// Route : déplacements plus rapides
+    Road,
+    /// Pont construit au-dessus d'un obstacle
+    Bridge,
+    /// Balise : observe les alentours en permanence
+    Beacon,
+    /// Dépôt où les collecteurs déchargent loin de la base
+    Depot,
 }
 
 impl Tile {
@@ -19,6 +29,22 @@ impl Tile {
             Tile::Science => 'S', //'🧬',
             Tile::Base => 'B', //'🏠',
             Tile::Robot => 'R', //'🤖',
+            Tile::Road => '=',
+            Tile::Bridge => 'H',
+            Tile::Beacon => '^',
+            Tile::Depot => 'D',
         }
     }
-}
\ No newline at end of file
+
+    pub fn is_walkable(self) -> bool {
+        matches!(self,
+            Tile::Empty | Tile::Energy | Tile::Mineral | Tile::Science | Tile::Base
+                | Tile::Road | Tile::Bridge | Tile::Beacon | Tile::Depot
+        )
+    }
+
+    /// Cases aménagées sur lesquelles les robots roulent plus vite.
+    pub fn is_paved(self) -> bool {
+        matches!(self, Tile::Road | Tile::Bridge)
+    }
+}
@@ -0,0 +1,65 @@
+use super::tile::Tile;
+
+/// Cases traversées par le segment entre deux points (algorithme de Bresenham),
+/// extrémités comprises.
+pub fn bresenham_line(from: (usize, usize), to: (usize, usize)) -> Vec<(usize, usize)> {
+    let (mut x0, mut y0) = (from.0 as isize, from.1 as isize);
+    let (x1, y1) = (to.0 as isize, to.1 as isize);
+
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    let mut points = Vec::new();
+    loop {
+        points.push((x0 as usize, y0 as usize));
+        if x0 == x1 && y0 == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x0 += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y0 += sy;
+        }
+    }
+    points
+}
+
+/// Vrai si aucun obstacle ne se trouve strictement entre les deux cases.
+pub fn has_line_of_sight(map: &[Vec<Tile>], from: (usize, usize), to: (usize, usize)) -> bool {
+    let line = bresenham_line(from, to);
+    if line.len() <= 2 {
+        return true;
+    }
+    line[1..line.len() - 1]
+        .iter()
+        .all(|&(x, y)| map[y][x] != Tile::Obstacle)
+}
+
+/// Toutes les cases dans le rayon du capteur et en ligne de vue depuis `origin`.
+/// Les obstacles eux-mêmes sont visibles mais masquent ce qui se trouve derrière.
+pub fn visible_tiles(map: &[Vec<Tile>], origin: (usize, usize), radius: usize) -> Vec<(usize, usize)> {
+    let (ox, oy) = origin;
+    let radius_sq = radius.saturating_mul(radius);
+    let mut tiles = Vec::new();
+    if map.is_empty() {
+        return tiles;
+    }
+
+    // Bornes exclusives : une ligne vide ne donne aucune case
+    for y in oy.saturating_sub(radius)..usize::min(oy.saturating_add(radius).saturating_add(1), map.len()) {
+        for x in ox.saturating_sub(radius)..usize::min(ox.saturating_add(radius).saturating_add(1), map[y].len()) {
+            let (dx, dy) = (x.abs_diff(ox), y.abs_diff(oy));
+            if dx * dx + dy * dy <= radius_sq && has_line_of_sight(map, origin, (x, y)) {
+                tiles.push((x, y));
+            }
+        }
+    }
+    tiles
+}
@@ -0,0 +1,5 @@
+pub mod recorder;
+#[cfg(test)]
+mod tests;
+
+pub use recorder::{Metrics, MetricsSample, MAX_RECENT_SAMPLES, STOCK_RESOURCES};
\ No newline at end of file
@@ -0,0 +1,226 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use crate::events::{EventReceiver, GameEvent};
+use crate::game::GameState;
+use crate::map::tile::Tile;
+use crate::robot::{RobotId, RobotState};
+
+// Relevés gardés pour l'interface ; l'historique complet, s'il est conservé, sert à l'export
+pub const MAX_RECENT_SAMPLES: usize = 120;
+
+/// Ressources suivies dans le stock de la base, dans l'ordre des colonnes.
+pub const STOCK_RESOURCES: [Tile; 3] = [Tile::Energy, Tile::Mineral, Tile::Science];
+
+/// État de la partie à la fin d'un tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsSample {
+    pub tick: u64,
+    pub stock: [u32; STOCK_RESOURCES.len()],
+    pub robots_by_state: [usize; RobotState::KEYS.len()], // Dans l'ordre de `RobotState::KEYS`
+    pub explored_percent: f64,
+    pub trips: u64, // Trajets terminés depuis le début des relevés
+    pub average_trip_length: f64, // En cases parcourues
+    pub average_trip_time: f64, // En ticks
+    pub path_searches: u64, // Recherches de chemin pendant ce tick
+    pub path_search_cost: u64, // Cases explorées par ces recherches
+    pub deliveries: Vec<(RobotId, u32)>, // Ressources livrées pendant ce tick, par robot
+}
+
+/// Trajet en cours d'un robot : depuis sa dernière livraison, ou depuis le début des relevés.
+#[derive(Debug, Clone, Copy)]
+struct Trip {
+    start_tick: u64,
+    length: u64,
+    position: (usize, usize),
+}
+
+/// Relevés pris à chaque tick. Un trajet se termine à chaque livraison, à la base
+/// ou dans un dépôt, et compte les cases parcourues depuis la précédente.
+pub struct Metrics {
+    events: EventReceiver,
+    recent: VecDeque<MetricsSample>,
+    history: Vec<MetricsSample>,
+    keep_history: bool,
+    trips: HashMap<RobotId, Trip>,
+    path_counters: HashMap<RobotId, (u64, u64)>, // Compteurs de recherche au relevé précédent
+    completed_trips: u64,
+    total_trip_length: u64,
+    total_trip_time: u64,
+    delivered_by_robot: BTreeMap<RobotId, u32>,
+}
+
+impl Metrics {
+    /// `events` : abonnement à la partie, voir `GameState::subscribe`.
+    /// Seuls les derniers relevés sont gardés, pour l'affichage.
+    pub fn new(game: &GameState, events: EventReceiver) -> Self {
+        Self::create(game, events, false)
+    }
+
+    /// Comme `new`, mais garde aussi tous les relevés pour les exporter.
+    pub fn with_history(game: &GameState, events: EventReceiver) -> Self {
+        Self::create(game, events, true)
+    }
+
+    fn create(game: &GameState, events: EventReceiver, keep_history: bool) -> Self {
+        let mut metrics = Self {
+            events,
+            recent: VecDeque::new(),
+            history: Vec::new(),
+            keep_history,
+            trips: HashMap::new(),
+            path_counters: HashMap::new(),
+            completed_trips: 0,
+            total_trip_length: 0,
+            total_trip_time: 0,
+            delivered_by_robot: BTreeMap::new(),
+        };
+        metrics.resync(game);
+        metrics
+    }
+
+    /// Repart des robots de `game` sans effacer les relevés, par exemple après un chargement :
+    /// les trajets en cours recommencent et les compteurs de recherche servent de nouvelle base.
+    pub fn resync(&mut self, game: &GameState) {
+        let tick = game.get_tick();
+        self.events.try_iter().for_each(drop);
+        self.trips = game.get_robots()
+            .iter()
+            .map(|robot| (robot.id, Trip { start_tick: tick, length: 0, position: (robot.x, robot.y) }))
+            .collect();
+        self.path_counters = game.get_robots()
+            .iter()
+            .map(|robot| (robot.id, (robot.path_searches, robot.path_search_cost)))
+            .collect();
+    }
+
+    /// À appeler après chaque mise à jour de la partie.
+    pub fn sample(&mut self, game: &GameState) {
+        let tick = game.get_tick();
+        let mut robots_by_state = [0; RobotState::KEYS.len()];
+        let (mut path_searches, mut path_search_cost) = (0, 0);
+        for robot in game.get_robots().iter() {
+            robots_by_state[robot.state.index()] += 1;
+
+            let trip = self.trips
+                .entry(robot.id)
+                .or_insert(Trip { start_tick: tick, length: 0, position: (robot.x, robot.y) });
+            trip.length += (robot.x.abs_diff(trip.position.0) + robot.y.abs_diff(trip.position.1)) as u64;
+            trip.position = (robot.x, robot.y);
+
+            let counters = (robot.path_searches, robot.path_search_cost);
+            let previous = self.path_counters.insert(robot.id, counters).unwrap_or((0, 0));
+            path_searches += counters.0.saturating_sub(previous.0);
+            path_search_cost += counters.1.saturating_sub(previous.1);
+        }
+
+        // Livraisons du tick, une fois les déplacements comptés
+        let mut deliveries = Vec::new();
+        let events: Vec<(u64, GameEvent)> = self.events.try_iter().collect();
+        for (_, event) in events {
+            if let GameEvent::CargoDelivered { robot_id, resources, .. } = event {
+                deliveries.push((robot_id, resources.len() as u32));
+                *self.delivered_by_robot.entry(robot_id).or_insert(0) += resources.len() as u32;
+                if let Some(trip) = self.trips.get_mut(&robot_id) {
+                    self.completed_trips += 1;
+                    self.total_trip_length += trip.length;
+                    self.total_trip_time += tick - trip.start_tick;
+                    trip.start_tick = tick;
+                    trip.length = 0;
+                }
+            }
+        }
+
+        let resources = game.get_base_resources();
+        let average = |total: u64| match self.completed_trips {
+            0 => 0.0,
+            trips => total as f64 / trips as f64,
+        };
+        let sample = MetricsSample {
+            tick,
+            stock: STOCK_RESOURCES.map(|tile| resources.get(&tile).copied().unwrap_or(0)),
+            robots_by_state,
+            explored_percent: game.get_coverage() * 100.0,
+            trips: self.completed_trips,
+            average_trip_length: average(self.total_trip_length),
+            average_trip_time: average(self.total_trip_time),
+            path_searches,
+            path_search_cost,
+            deliveries,
+        };
+
+        self.recent.push_back(sample.clone());
+        while self.recent.len() > MAX_RECENT_SAMPLES {
+            self.recent.pop_front();
+        }
+        if self.keep_history {
+            self.history.push(sample);
+        }
+    }
+
+    /// Derniers relevés, du plus ancien au plus récent.
+    pub fn recent(&self) -> &VecDeque<MetricsSample> {
+        &self.recent
+    }
+
+    /// Tous les relevés depuis la création ; vide sans `with_history`.
+    pub fn history(&self) -> &[MetricsSample] {
+        &self.history
+    }
+
+    pub fn latest(&self) -> Option<&MetricsSample> {
+        self.recent.back()
+    }
+
+    /// Ressources livrées par chaque robot depuis le début des relevés.
+    pub fn delivered_by_robot(&self) -> &BTreeMap<RobotId, u32> {
+        &self.delivered_by_robot
+    }
+
+    /// Exporte tout l'historique, une ligne par tick. Les colonnes `delivered_<id>`
+    /// cumulent les livraisons de chaque robot ayant livré au moins une fois.
+    pub fn write_csv(&self, out: &mut impl Write) -> io::Result<()> {
+        let robots: Vec<RobotId> = self.delivered_by_robot.keys().copied().collect();
+        let mut header = vec!["tick".to_string()];
+        header.extend(["stock_energy", "stock_mineral", "stock_science"].map(String::from));
+        header.extend(RobotState::KEYS.iter().map(|key| format!("robots_{}", key)));
+        header.extend(
+            [
+                "explored_percent",
+                "trips",
+                "average_trip_length",
+                "average_trip_time",
+                "path_searches",
+                "path_search_cost",
+            ]
+            .map(String::from),
+        );
+        header.extend(robots.iter().map(|id| format!("delivered_{}", id.0)));
+        writeln!(out, "{}", header.join(","))?;
+
+        let mut delivered: BTreeMap<RobotId, u32> = robots.iter().map(|&id| (id, 0)).collect();
+        for sample in &self.history {
+            for &(robot_id, count) in &sample.deliveries {
+                *delivered.entry(robot_id).or_insert(0) += count;
+            }
+            let mut row = vec![sample.tick.to_string()];
+            row.extend(sample.stock.iter().map(u32::to_string));
+            row.extend(sample.robots_by_state.iter().map(usize::to_string));
+            row.push(format!("{:.2}", sample.explored_percent));
+            row.push(sample.trips.to_string());
+            row.push(format!("{:.2}", sample.average_trip_length));
+            row.push(format!("{:.2}", sample.average_trip_time));
+            row.push(sample.path_searches.to_string());
+            row.push(sample.path_search_cost.to_string());
+            row.extend(delivered.values().map(u32::to_string));
+            writeln!(out, "{}", row.join(","))?;
+        }
+        Ok(())
+    }
+
+    pub fn save_csv(&self, path: &str) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut out)?;
+        out.flush()
+    }
+}
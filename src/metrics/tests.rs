@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod metrics_tests {
+    use super::super::{Metrics, MAX_RECENT_SAMPLES};
+    use crate::game::GameState;
+    use crate::map::tile::Tile;
+    use crate::robot::RobotState;
+
+    fn run(game: &mut GameState, metrics: &mut Metrics, ticks: u64) {
+        for _ in 0..ticks {
+            game.update();
+            metrics.sample(game);
+        }
+    }
+
+    #[test]
+    fn test_samples_every_tick() {
+        let mut game = GameState::new(40, 40, 42);
+        let events = game.subscribe();
+        let mut metrics = Metrics::with_history(&game, events);
+        run(&mut game, &mut metrics, MAX_RECENT_SAMPLES as u64 + 180);
+
+        let history = metrics.history();
+        assert_eq!(history.len(), MAX_RECENT_SAMPLES + 180);
+        assert!(history.windows(2).all(|pair| pair[1].tick == pair[0].tick + 1));
+        assert_eq!(metrics.recent().len(), MAX_RECENT_SAMPLES);
+        assert_eq!(metrics.latest(), history.last());
+
+        let latest = metrics.latest().unwrap();
+        assert_eq!(latest.robots_by_state.iter().sum::<usize>(), game.get_robots().len());
+        let resources = game.get_base_resources();
+        assert_eq!(latest.stock[1], resources.get(&Tile::Mineral).copied().unwrap_or(0));
+        assert!(history.iter().map(|sample| sample.path_searches).sum::<u64>() > 0);
+        assert!(history.iter().all(|sample| sample.path_search_cost >= sample.path_searches));
+
+        // Chaque livraison termine un trajet, et les totaux par robot les additionnent
+        let delivered: u32 = metrics.delivered_by_robot().values().sum();
+        let deliveries: u32 = history.iter().flat_map(|sample| &sample.deliveries).map(|&(_, count)| count).sum();
+        assert!(delivered > 0);
+        assert_eq!(delivered, deliveries);
+        assert!(latest.trips > 0 && latest.average_trip_length > 0.0 && latest.average_trip_time > 0.0);
+    }
+
+    #[test]
+    fn test_csv_export() {
+        let mut game = GameState::new(40, 40, 42);
+        let events = game.subscribe();
+        let mut metrics = Metrics::with_history(&game, events);
+        run(&mut game, &mut metrics, 200);
+
+        let mut out = Vec::new();
+        metrics.write_csv(&mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 201);
+
+        let header: Vec<&str> = lines[0].split(',').collect();
+        assert_eq!(header[0], "tick");
+        assert!(header.contains(&"robots_mining"));
+        let robots = metrics.delivered_by_robot();
+        assert_eq!(header.iter().filter(|column| column.starts_with("delivered_")).count(), robots.len());
+        assert!(lines[1..].iter().all(|line| line.split(',').count() == header.len()));
+
+        // La dernière ligne cumule les livraisons de chaque robot
+        let last: Vec<&str> = lines[200].split(',').collect();
+        assert_eq!(last[0], "200");
+        for (id, total) in robots {
+            let column = header.iter().position(|column| *column == format!("delivered_{}", id.0)).unwrap();
+            assert_eq!(last[column], total.to_string());
+        }
+        let mining = header.iter().position(|column| *column == "robots_mining").unwrap();
+        assert_eq!(RobotState::KEYS[mining - 4], "mining");
+    }
+
+    #[test]
+    fn test_history_only_kept_for_export() {
+        let mut game = GameState::new(40, 40, 42);
+        let events = game.subscribe();
+        let mut metrics = Metrics::new(&game, events);
+        run(&mut game, &mut metrics, MAX_RECENT_SAMPLES as u64 + 10);
+
+        assert!(metrics.history().is_empty());
+        assert_eq!(metrics.recent().len(), MAX_RECENT_SAMPLES);
+        assert_eq!(metrics.latest().unwrap().tick, MAX_RECENT_SAMPLES as u64 + 10);
+    }
+}
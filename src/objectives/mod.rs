@@ -0,0 +1,7 @@
+pub mod objective;
+pub mod outcome;
+#[cfg(test)]
+mod tests;
+
+pub use objective::{Objective, ObjectiveProgress, ObjectiveStatus};
+pub use outcome::{DefeatReason, GameOutcome, Outcome, Score};
\ No newline at end of file
@@ -0,0 +1,121 @@
+use std::fmt;
+use serde::Serialize;
+use crate::config::ObjectiveSettings;
+use crate::game::GameState;
+use crate::map::tile::Tile;
+use super::outcome::{DefeatReason, Outcome};
+
+/// Objectif de partie, tiré des `ObjectiveSettings` du scénario.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum Objective {
+    Deliver { resource: Tile, amount: u32 },
+    Explore { percent: f64 },
+    Survive { ticks: u64 },
+    /// Livraisons et exploration à terminer avant ce tick
+    Deadline { tick: u64 },
+    KeepEnergy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectiveStatus {
+    InProgress,
+    Done,
+    Failed,
+}
+
+/// Avancement d'un objectif : `current` progresse vers `target`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ObjectiveProgress {
+    pub objective: Objective,
+    pub current: f64,
+    pub target: f64,
+    pub status: ObjectiveStatus,
+}
+
+impl Objective {
+    /// Objectifs actifs, dans l'ordre d'affichage.
+    pub fn list(settings: &ObjectiveSettings) -> Vec<Objective> {
+        let delivered = settings.delivered;
+        let mut objectives: Vec<Objective> = [
+            (Tile::Energy, delivered.energy),
+            (Tile::Mineral, delivered.mineral),
+            (Tile::Science, delivered.science),
+        ]
+        .into_iter()
+        .filter(|&(_, amount)| amount > 0)
+        .map(|(resource, amount)| Objective::Deliver { resource, amount })
+        .collect();
+        if settings.explored_percent > 0.0 {
+            objectives.push(Objective::Explore { percent: settings.explored_percent });
+        }
+        if settings.survive_ticks > 0 {
+            objectives.push(Objective::Survive { ticks: settings.survive_ticks });
+        }
+        if settings.deadline > 0 {
+            objectives.push(Objective::Deadline { tick: settings.deadline });
+        }
+        if settings.keep_energy {
+            objectives.push(Objective::KeepEnergy);
+        }
+        objectives
+    }
+
+    /// Condition à remplir pour gagner, par opposition aux conditions de défaite.
+    pub fn is_goal(&self) -> bool {
+        matches!(self, Objective::Deliver { .. } | Objective::Explore { .. } | Objective::Survive { .. })
+    }
+
+    pub fn progress(&self, game: &GameState) -> ObjectiveProgress {
+        let outcome = game.get_outcome().map(|outcome| outcome.outcome);
+        let elapsed = game.get_tick() as f64;
+        let (current, target) = match *self {
+            Objective::Deliver { resource, amount } => {
+                (game.get_delivered().get(&resource).copied().unwrap_or(0) as f64, amount as f64)
+            }
+            Objective::Explore { percent } => (game.get_coverage() * 100.0, percent),
+            Objective::Survive { ticks } => (elapsed, ticks as f64),
+            Objective::Deadline { tick } => (elapsed, tick as f64),
+            Objective::KeepEnergy => (game.get_base_resources().get(&Tile::Energy).copied().unwrap_or(0) as f64, 0.0),
+        };
+
+        let status = match (self, outcome) {
+            (Objective::Deadline { .. } | Objective::KeepEnergy, Some(Outcome::Victory)) => ObjectiveStatus::Done,
+            (Objective::Deadline { .. }, Some(Outcome::Defeat(DefeatReason::DeadlineMissed))) => ObjectiveStatus::Failed,
+            (Objective::KeepEnergy, Some(Outcome::Defeat(DefeatReason::EnergyDepleted))) => ObjectiveStatus::Failed,
+            (Objective::Deadline { .. } | Objective::KeepEnergy, _) => ObjectiveStatus::InProgress,
+            _ if current >= target => ObjectiveStatus::Done,
+            (_, Some(Outcome::Defeat(_))) => ObjectiveStatus::Failed,
+            _ => ObjectiveStatus::InProgress,
+        };
+        ObjectiveProgress { objective: *self, current, target, status }
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Objective::Deliver { resource, amount } => write!(f, "Livrer {} {}", amount, resource.to_char()),
+            Objective::Explore { percent } => write!(f, "Explorer {:.0}% de la carte", percent),
+            Objective::Survive { ticks } => write!(f, "Tenir {} ticks", ticks),
+            Objective::Deadline { tick } => write!(f, "Avant le tick {}", tick),
+            Objective::KeepEnergy => write!(f, "Garder de l'énergie"),
+        }
+    }
+}
+
+impl fmt::Display for ObjectiveProgress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mark = match self.status {
+            ObjectiveStatus::InProgress => ' ',
+            ObjectiveStatus::Done => '+',
+            ObjectiveStatus::Failed => 'x',
+        };
+        match self.objective {
+            Objective::Explore { .. } => write!(f, "[{}] {} ({:.1}%)", mark, self.objective, self.current),
+            Objective::KeepEnergy => write!(f, "[{}] {} ({})", mark, self.objective, self.current),
+            _ => write!(f, "[{}] {} ({}/{})", mark, self.objective, self.current, self.target),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+// Barème du score final
+pub const VICTORY_POINTS: i64 = 1000;
+pub const MAX_TIME_POINTS: i64 = 1000;
+pub const TICKS_PER_TIME_POINT: u64 = 10;
+pub const MAX_EFFICIENCY_POINTS: f64 = 500.0;
+pub const ROBOT_LOST_PENALTY: i64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DefeatReason {
+    /// Stock d'énergie de la base épuisé alors qu'il fallait le préserver
+    EnergyDepleted,
+    /// Échéance atteinte sans avoir rempli les objectifs
+    DeadlineMissed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Victory,
+    Defeat(DefeatReason),
+}
+
+impl Outcome {
+    pub fn is_victory(&self) -> bool {
+        matches!(self, Outcome::Victory)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Victory => write!(f, "Victoire"),
+            Outcome::Defeat(DefeatReason::EnergyDepleted) => write!(f, "Défaite : énergie de la base épuisée"),
+            Outcome::Defeat(DefeatReason::DeadlineMissed) => write!(f, "Défaite : échéance dépassée"),
+        }
+    }
+}
+
+/// Score final, détaillé par critère. Une victoire rapide rapporte davantage ;
+/// en cas de défaite, c'est au contraire la durée tenue qui compte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Score {
+    pub outcome: i64,
+    pub time: i64,
+    pub efficiency: i64, // Part du temps passée par les robots à travailler plutôt qu'à attendre
+    pub robots_lost: i64, // Pénalité, négative ou nulle
+}
+
+impl Score {
+    /// `tick` : ticks écoulés ; `idle_ratio` : voir `GameState::get_idle_ratio`.
+    pub fn new(outcome: Outcome, tick: u64, idle_ratio: f64, robots_lost: u32) -> Self {
+        let elapsed_points = ((tick / TICKS_PER_TIME_POINT) as i64).min(MAX_TIME_POINTS);
+        Self {
+            outcome: if outcome.is_victory() { VICTORY_POINTS } else { 0 },
+            time: if outcome.is_victory() { MAX_TIME_POINTS - elapsed_points } else { elapsed_points },
+            efficiency: ((1.0 - idle_ratio).clamp(0.0, 1.0) * MAX_EFFICIENCY_POINTS).round() as i64,
+            robots_lost: -ROBOT_LOST_PENALTY * robots_lost as i64,
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        (self.outcome + self.time + self.efficiency + self.robots_lost).max(0) as u64
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} (issue {:+}, temps {:+}, efficacité {:+}, robots perdus {:+})",
+            self.total(),
+            self.outcome,
+            self.time,
+            self.efficiency,
+            self.robots_lost
+        )
+    }
+}
+
+/// Fin de partie, fixée au premier tick où elle est décidée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameOutcome {
+    pub tick: u64,
+    pub outcome: Outcome,
+    pub score: Score,
+}
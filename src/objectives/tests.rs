@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod objectives_tests {
+    use super::super::{DefeatReason, Objective, ObjectiveStatus, Outcome, Score};
+    use crate::config::{ResourceAmounts, SetupError, WorldConfig};
+    use crate::events::GameEvent;
+    use crate::game::GameState;
+    use crate::map::tile::Tile;
+    use crate::robot::RobotType;
+
+    fn small_world() -> WorldConfig {
+        WorldConfig { width: 30, height: 30, ..WorldConfig::default() }
+    }
+
+    #[test]
+    fn test_survive_objective() {
+        let mut config = small_world();
+        config.objectives.survive_ticks = 5;
+        let mut game = GameState::with_config(&config).unwrap();
+        let receiver = game.subscribe();
+        for _ in 0..4 {
+            game.update();
+        }
+        assert!(game.get_outcome().is_none());
+        assert_eq!(game.get_objective_progress()[0].status, ObjectiveStatus::InProgress);
+
+        game.update();
+        let outcome = *game.get_outcome().unwrap();
+        assert_eq!((outcome.tick, outcome.outcome), (4, Outcome::Victory));
+        assert_eq!(game.get_victory_tick(), Some(4));
+        assert_eq!(game.get_objective_progress()[0].status, ObjectiveStatus::Done);
+
+        // L'issue est figée et annoncée une seule fois
+        for _ in 0..3 {
+            game.update();
+        }
+        assert_eq!(game.get_outcome(), Some(&outcome));
+        let game_over: Vec<GameEvent> = receiver.try_iter()
+            .map(|(_, event)| event)
+            .filter(|event| matches!(event, GameEvent::GameOver { .. }))
+            .collect();
+        assert_eq!(game_over, vec![GameEvent::GameOver { outcome: Outcome::Victory }]);
+    }
+
+    #[test]
+    fn test_deadline_defeat() {
+        let mut config = small_world();
+        config.objectives.delivered.science = 1000;
+        config.objectives.deadline = 10;
+        let mut game = GameState::with_config(&config).unwrap();
+        for _ in 0..10 {
+            game.update();
+        }
+
+        let outcome = game.get_outcome().unwrap();
+        assert_eq!(outcome.outcome, Outcome::Defeat(DefeatReason::DeadlineMissed));
+        assert_eq!(outcome.tick, 9);
+        assert_eq!(game.get_victory_tick(), None);
+        let statuses: Vec<(Objective, ObjectiveStatus)> = game.get_objective_progress()
+            .into_iter()
+            .map(|progress| (progress.objective, progress.status))
+            .collect();
+        assert_eq!(statuses, vec![
+            (Objective::Deliver { resource: Tile::Science, amount: 1000 }, ObjectiveStatus::Failed),
+            (Objective::Deadline { tick: 10 }, ObjectiveStatus::Failed),
+        ]);
+    }
+
+    #[test]
+    fn test_energy_depletion_defeat() {
+        let mut config = small_world();
+        config.base.starting_resources = ResourceAmounts { energy: 3, mineral: 2, science: 0 };
+        config.objectives.keep_energy = true;
+        let mut game = GameState::with_config(&config).unwrap();
+        game.update();
+        assert!(game.get_outcome().is_none());
+
+        // Un explorateur coûte toute l'énergie de la base
+        game.queue_robot(RobotType::Explorer).unwrap();
+        game.update();
+        assert_eq!(game.get_outcome().unwrap().outcome, Outcome::Defeat(DefeatReason::EnergyDepleted));
+
+        config.base.starting_resources.energy = 0;
+        assert!(matches!(GameState::with_config(&config), Err(SetupError::InvalidParameter { .. })));
+    }
+
+    #[test]
+    fn test_score() {
+        let victory = Score::new(Outcome::Victory, 2000, 0.25, 1);
+        assert_eq!((victory.outcome, victory.time, victory.efficiency, victory.robots_lost), (1000, 800, 375, -100));
+        assert_eq!(victory.total(), 2075);
+
+        // Une victoire plus rapide rapporte plus ; une défaite plus tardive aussi
+        assert!(Score::new(Outcome::Victory, 500, 0.25, 1).total() > victory.total());
+        let defeat = |tick| Score::new(Outcome::Defeat(DefeatReason::DeadlineMissed), tick, 1.0, 0).total();
+        assert!(defeat(3000) > defeat(1000));
+        assert_eq!(Score::new(Outcome::Defeat(DefeatReason::EnergyDepleted), 0, 1.0, 5).total(), 0);
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use crate::allocation::AllocationStrategy;
+use crate::base::AutobuildPolicy;
+use crate::exploration::ExplorationStrategy;
+use crate::game::GameState;
+use crate::robot::{RobotType, Upgrade, UpgradeTarget};
+
+/// Commande du joueur qui modifie la simulation. Les valeurs sont absolues
+/// (pas de bascule) pour qu'une relecture ne dépende que de la commande elle-même.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Input {
+    SetExplorationStrategy(ExplorationStrategy),
+    SetAllocationStrategy(AllocationStrategy),
+    PurchaseUpgrade { target: UpgradeTarget, upgrade: Upgrade },
+    QueueRobot(RobotType),
+    PlanRoad,
+    PlanDepot,
+    SetAutobuild(Option<AutobuildPolicy>),
+}
+
+impl Input {
+    /// Applique la commande ; une commande refusée (ressources, file pleine...) ne change rien.
+    pub fn apply(&self, game: &mut GameState) {
+        match self {
+            Input::SetExplorationStrategy(strategy) => game.set_exploration_strategy(*strategy),
+            Input::SetAllocationStrategy(strategy) => game.set_allocation_strategy(*strategy),
+            Input::PurchaseUpgrade { target, upgrade } => {
+                let _ = game.purchase_upgrade(*target, *upgrade);
+            }
+            Input::QueueRobot(robot_type) => {
+                let _ = game.queue_robot(*robot_type);
+            }
+            Input::PlanRoad => {
+                game.plan_road_to_cluster();
+            }
+            Input::PlanDepot => {
+                let _ = game.plan_depot();
+            }
+            Input::SetAutobuild(policy) => game.set_autobuild(policy.clone()),
+        }
+    }
+}
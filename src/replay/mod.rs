@@ -0,0 +1,12 @@
+pub mod input;
+pub mod player;
+pub mod recording;
+#[cfg(test)]
+mod tests;
+
+pub use input::Input;
+pub use player::ReplayPlayer;
+pub use recording::{
+    Checkpoint, RecordedInput, Replay, ReplayError, ReplayRecorder, Snapshot, CHECKPOINT_INTERVAL, REPLAY_FORMAT_VERSION,
+    SNAPSHOT_INTERVAL,
+};
\ No newline at end of file
@@ -0,0 +1,103 @@
+use crate::events::EventReceiver;
+use crate::game::GameState;
+use crate::save::{self, state_hash};
+use super::recording::{Replay, ReplayError};
+
+/// Rejoue un enregistrement tick par tick. Un déplacement repart de l'instantané
+/// enregistré le plus proche avant la cible, ou du début de la partie.
+pub struct ReplayPlayer {
+    replay: Replay,
+    tick_rate: f64,
+    game: GameState,
+    divergence: Option<u64>, // Premier tick dont l'empreinte diffère de l'enregistrement
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> Result<Self, ReplayError> {
+        let (scenario, game) = replay.initial_game()?;
+        Ok(Self { replay, tick_rate: scenario.tick_rate, game, divergence: None })
+    }
+
+    pub fn game(&self) -> &GameState {
+        &self.game
+    }
+
+    /// Abonnement aux événements rejoués, conservé d'un déplacement à l'autre.
+    pub fn subscribe(&mut self) -> EventReceiver {
+        self.game.subscribe()
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.game.get_tick()
+    }
+
+    pub fn end_tick(&self) -> u64 {
+        self.replay.end_tick
+    }
+
+    /// Cadence du scénario enregistré, en ticks par seconde à vitesse 1x.
+    pub fn tick_rate(&self) -> f64 {
+        self.tick_rate
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.tick() >= self.end_tick()
+    }
+
+    /// `Some(tick)` si la simulation ne reproduit plus la partie enregistrée,
+    /// par exemple après un changement des règles depuis l'enregistrement.
+    pub fn divergence(&self) -> Option<u64> {
+        self.divergence
+    }
+
+    /// Rejoue un tick : les commandes du joueur, puis la mise à jour.
+    /// Ne fait rien une fois la fin de l'enregistrement atteinte.
+    pub fn step(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+        let tick = self.tick();
+        let inputs = &self.replay.inputs;
+        let first = inputs.partition_point(|recorded| recorded.tick < tick);
+        for recorded in inputs[first..].iter().take_while(|recorded| recorded.tick == tick) {
+            recorded.input.apply(&mut self.game);
+        }
+        self.game.update();
+
+        let tick = self.tick();
+        if self.divergence.is_none() {
+            let checkpoints = &self.replay.checkpoints;
+            if let Ok(index) = checkpoints.binary_search_by_key(&tick, |checkpoint| checkpoint.tick) {
+                if checkpoints[index].hash != state_hash(&self.game) {
+                    self.divergence = Some(tick);
+                }
+            }
+        }
+    }
+
+    /// Place la relecture au tick `target`, borné à la fin de l'enregistrement.
+    /// Repart du dernier instantané avant la cible s'il fait gagner du temps ; un
+    /// instantané illisible (format de sauvegarde plus ancien) est ignoré.
+    pub fn seek(&mut self, target: u64) {
+        let target = target.min(self.end_tick());
+        let current = self.tick();
+        let snapshots = &self.replay.snapshots;
+        let restored = snapshots[..snapshots.partition_point(|snapshot| snapshot.tick <= target)]
+            .iter()
+            .rev()
+            .take_while(|snapshot| target < current || snapshot.tick > current)
+            .find_map(|snapshot| save::from_json(&snapshot.state).ok());
+        let restored = match restored {
+            Some(game) => Some(game),
+            None if target < current => self.replay.initial_game().ok().map(|(_, game)| game),
+            None => None,
+        };
+        if let Some(mut restored) = restored {
+            restored.take_subscribers(&mut self.game);
+            self.game = restored;
+        }
+        while self.tick() < target {
+            self.step();
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::fs;
+use serde::{Deserialize, Serialize};
+use crate::config::{ConfigError, Scenario};
+use crate::game::GameState;
+use crate::save::{self, state_hash};
+use super::input::Input;
+
+/// Version du format d'enregistrement, à incrémenter à chaque changement incompatible.
+pub const REPLAY_FORMAT_VERSION: u32 = 2;
+
+// Écart entre deux empreintes de contrôle de l'état, en ticks
+pub const CHECKPOINT_INTERVAL: u64 = 100;
+// Écart entre deux instantanés enregistrés, d'où repart un déplacement dans la relecture
+pub const SNAPSHOT_INTERVAL: u64 = 500;
+
+/// Commande du joueur, appliquée juste avant la mise à jour du tick `tick`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedInput {
+    pub tick: u64,
+    pub input: Input,
+}
+
+/// Empreinte de l'état après `tick` ticks, pour repérer une relecture qui diverge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub tick: u64,
+    pub hash: u64,
+}
+
+/// Sauvegarde complète de la partie après `tick` ticks, voir `save::to_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub tick: u64,
+    pub state: String,
+}
+
+/// Partie enregistrée : le scénario de départ (graine comprise), puis les seules
+/// commandes du joueur. La simulation étant déterministe, cela suffit à la rejouer ;
+/// les instantanés évitent seulement de tout resimuler pour atteindre un tick lointain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub scenario: String, // Scénario au format TOML, voir `Scenario::to_toml`
+    pub inputs: Vec<RecordedInput>,
+    pub checkpoints: Vec<Checkpoint>,
+    pub snapshots: Vec<Snapshot>, // Triés par tick
+    pub end_tick: u64,
+}
+
+impl Replay {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("un enregistrement se sérialise toujours")
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, ReplayError> {
+        let replay: Replay = serde_json::from_str(text).map_err(|error| ReplayError::Parse(error.to_string()))?;
+        if replay.version != REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion(replay.version));
+        }
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), ReplayError> {
+        fs::write(path, self.to_json())
+            .map_err(|error| ReplayError::Io { path: path.to_string(), message: error.to_string() })
+    }
+
+    pub fn load(path: &str) -> Result<Self, ReplayError> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| ReplayError::Io { path: path.to_string(), message: error.to_string() })?;
+        Self::from_json(&text)
+    }
+
+    /// Scénario et partie au tick 0, tels qu'au début de l'enregistrement.
+    pub fn initial_game(&self) -> Result<(Scenario, GameState), ReplayError> {
+        let scenario = Scenario::from_toml(&self.scenario).map_err(ReplayError::Config)?;
+        let game = GameState::with_config(&scenario.world)
+            .map_err(|error| ReplayError::Config(ConfigError::Invalid(error)))?;
+        Ok((scenario, game))
+    }
+}
+
+/// Enregistre une partie créée à partir d'un scénario, pendant qu'elle se joue.
+#[derive(Debug, Clone)]
+pub struct ReplayRecorder {
+    replay: Replay,
+}
+
+impl ReplayRecorder {
+    pub fn new(scenario: &Scenario) -> Self {
+        Self {
+            replay: Replay {
+                version: REPLAY_FORMAT_VERSION,
+                scenario: scenario.to_toml(),
+                inputs: Vec::new(),
+                checkpoints: Vec::new(),
+                snapshots: Vec::new(),
+                end_tick: 0,
+            },
+        }
+    }
+
+    /// Applique une commande du joueur et la note pour le tick en cours.
+    pub fn apply(&mut self, game: &mut GameState, input: Input) {
+        input.apply(game);
+        self.record(game.get_tick(), input);
+    }
+
+    /// Note une commande déjà appliquée au tick `tick`.
+    pub fn record(&mut self, tick: u64, input: Input) {
+        self.replay.inputs.push(RecordedInput { tick, input });
+    }
+
+    /// À appeler après chaque mise à jour de la partie.
+    pub fn after_tick(&mut self, game: &GameState) {
+        let tick = game.get_tick();
+        self.replay.end_tick = tick;
+        if tick.is_multiple_of(CHECKPOINT_INTERVAL) {
+            self.replay.checkpoints.push(Checkpoint { tick, hash: state_hash(game) });
+        }
+        if tick.is_multiple_of(SNAPSHOT_INTERVAL) {
+            self.replay.snapshots.push(Snapshot { tick, state: save::to_json(game) });
+        }
+    }
+
+    pub fn finish(self) -> Replay {
+        self.replay
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    Io { path: String, message: String },
+    /// JSON mal formé ou champ manquant
+    Parse(String),
+    UnsupportedVersion(u32),
+    /// Scénario enregistré illisible ou invalide
+    Config(ConfigError),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Io { path, message } => write!(f, "accès à {} impossible : {}", path, message),
+            ReplayError::Parse(message) => write!(f, "enregistrement invalide : {}", message),
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "enregistrement au format {} non pris en charge (format attendu : {})",
+                version, REPLAY_FORMAT_VERSION
+            ),
+            ReplayError::Config(error) => write!(f, "scénario de l'enregistrement invalide : {}", error),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod replay_tests {
+    use super::super::{Input, Replay, ReplayError, ReplayPlayer, ReplayRecorder, REPLAY_FORMAT_VERSION, SNAPSHOT_INTERVAL};
+    use crate::allocation::AllocationStrategy;
+    use crate::base::AutobuildPolicy;
+    use crate::config::{Scenario, WorldConfig};
+    use crate::exploration::ExplorationStrategy;
+    use crate::game::GameState;
+    use crate::robot::RobotType;
+    use crate::save::state_hash;
+
+    /// Joue `ticks` ticks avec quelques commandes ; renvoie l'enregistrement et
+    /// l'empreinte de l'état après chaque tick.
+    fn record(ticks: u64) -> (Replay, Vec<u64>) {
+        let scenario = Scenario {
+            world: WorldConfig { width: 40, height: 40, seed: 11, ..WorldConfig::default() },
+            ..Scenario::default()
+        };
+        let mut game = GameState::with_config(&scenario.world).unwrap();
+        let mut recorder = ReplayRecorder::new(&scenario);
+        let mut hashes = vec![state_hash(&game)];
+        for _ in 0..ticks {
+            let input = match game.get_tick() {
+                10 => Some(Input::SetAllocationStrategy(AllocationStrategy::Hungarian)),
+                30 => Some(Input::SetExplorationStrategy(ExplorationStrategy::RandomWalk)),
+                60 => Some(Input::QueueRobot(RobotType::Explorer)),
+                80 => Some(Input::SetAutobuild(Some(AutobuildPolicy::default()))),
+                _ => None,
+            };
+            if let Some(input) = input {
+                recorder.apply(&mut game, input);
+            }
+            game.update();
+            recorder.after_tick(&game);
+            hashes.push(state_hash(&game));
+        }
+        (recorder.finish(), hashes)
+    }
+
+    #[test]
+    fn test_replay_reproduces_and_seeks() {
+        let (replay, hashes) = record(580);
+        assert_eq!(replay.end_tick, 580);
+        assert_eq!(replay.inputs.len(), 4);
+        assert_eq!(replay.snapshots.iter().map(|snapshot| snapshot.tick).collect::<Vec<_>>(), vec![SNAPSHOT_INTERVAL]);
+
+        let mut player = ReplayPlayer::new(Replay::from_json(&replay.to_json()).unwrap()).unwrap();
+        player.seek(u64::MAX);
+        assert!(player.is_finished());
+        assert_eq!(player.tick(), 580);
+        assert_eq!(state_hash(player.game()), hashes[580]);
+        assert_eq!(player.divergence(), None);
+
+        // Retour en arrière par l'instantané du tick 500, puis avant les commandes
+        player.seek(560);
+        assert_eq!(state_hash(player.game()), hashes[560]);
+        player.seek(5);
+        assert_eq!(state_hash(player.game()), hashes[5]);
+        player.step();
+        assert_eq!(state_hash(player.game()), hashes[6]);
+    }
+
+    #[test]
+    fn test_seek_starts_from_recorded_snapshot() {
+        let (mut replay, hashes) = record(560);
+        // Sans les commandes, seul l'instantané enregistré permet de retrouver la partie
+        replay.inputs.clear();
+        let mut player = ReplayPlayer::new(replay.clone()).unwrap();
+        player.seek(530);
+        assert_eq!(state_hash(player.game()), hashes[530]);
+
+        // Un instantané illisible est ignoré : la relecture resimule depuis le début
+        replay.snapshots[0].state.clear();
+        let mut player = ReplayPlayer::new(replay).unwrap();
+        player.seek(530);
+        assert_eq!(player.tick(), 530);
+        assert_ne!(state_hash(player.game()), hashes[530]);
+    }
+
+    #[test]
+    fn test_divergence_and_version_are_reported() {
+        let (mut replay, _) = record(100);
+        replay.checkpoints[0].hash ^= 1;
+        let mut player = ReplayPlayer::new(replay.clone()).unwrap();
+        player.seek(100);
+        assert_eq!(player.divergence(), Some(100));
+
+        replay.version = REPLAY_FORMAT_VERSION + 1;
+        assert_eq!(Replay::from_json(&replay.to_json()), Err(ReplayError::UnsupportedVersion(REPLAY_FORMAT_VERSION + 1)));
+        assert!(matches!(Replay::from_json("[]"), Err(ReplayError::Parse(_))));
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+
+// Points de vie réparés par unité de Minéral
+pub const REPAIR_HP_PER_MINERAL: u32 = 250;
+
+/// Paramètres d'usure des robots.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WearConfig {
+    /// Points de vie perdus à chaque case parcourue
+    pub move_wear: u32,
+    /// Perte supplémentaire sur une case dangereuse (bordant un obstacle)
+    pub hazard_wear: u32,
+    /// Un point de vie perdu tous les `age_wear_interval` ticks d'âge (0 = jamais)
+    pub age_wear_interval: u64,
+    /// Probabilité de panne par tick d'un robot intact ; doublée pour un robot à 0 PV
+    pub breakdown_chance: f64,
+}
+
+impl Default for WearConfig {
+    fn default() -> Self {
+        Self {
+            move_wear: 1,
+            hazard_wear: 1,
+            age_wear_interval: 50,
+            breakdown_chance: 0.0002,
+        }
+    }
+}
+
+impl WearConfig {
+    /// Usure causée par l'arrivée sur la case (x, y). Les routes protègent du danger.
+    pub fn move_cost(&self, map: &[Vec<Tile>], x: usize, y: usize) -> u32 {
+        if !map[y][x].is_paved() && is_hazardous(map, x, y) {
+            self.move_wear + self.hazard_wear
+        } else {
+            self.move_wear
+        }
+    }
+
+    /// Probabilité de panne ce tick, qui augmente avec les dégâts.
+    pub fn breakdown_probability(&self, hp: u32, max_hp: u32) -> f64 {
+        let damage = 1.0 - hp as f64 / max_hp.max(1) as f64;
+        (self.breakdown_chance * (1.0 + damage)).clamp(0.0, 1.0)
+    }
+}
+
+/// Une case est dangereuse si elle touche un obstacle (éboulis, crevasses).
+pub fn is_hazardous(map: &[Vec<Tile>], x: usize, y: usize) -> bool {
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+    directions.iter().any(|(dx, dy)| {
+        let nx = x as isize + dx;
+        let ny = y as isize + dy;
+        ny >= 0 && nx >= 0
+            && (ny as usize) < map.len()
+            && (nx as usize) < map[ny as usize].len()
+            && map[ny as usize][nx as usize] == Tile::Obstacle
+    })
+}
@@ -1,7 +1,15 @@
+pub mod durability;
+pub mod registry;
 pub mod robot;
+pub mod stats;
 pub mod types;
+pub mod upgrades;
 #[cfg(test)]
 mod tests;
 
-pub use robot::{Robot, RobotState};
-pub use types::RobotType;
\ No newline at end of file
+pub use durability::WearConfig;
+pub use robot::{MoveOutcome, Robot, RobotState};
+pub use registry::RobotRegistry;
+pub use stats::RobotStats;
+pub use types::{RobotId, RobotType};
+pub use upgrades::{Upgrade, UpgradeError, UpgradeSet, UpgradeTarget};
\ No newline at end of file
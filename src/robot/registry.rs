@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use super::robot::Robot;
+use super::types::{RobotId, RobotType};
+
+/// Stockage des robots indexé par identifiant stable.
+/// Les identifiants ne sont jamais réutilisés : un `RobotId` retiré reste invalide
+/// et ne peut pas désigner par erreur un robot créé plus tard. Les robots retirés
+/// libèrent leur place, les autres restent triés par identifiant.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RobotRegistry {
+    robots: Vec<Robot>,
+    next_id: u32,
+}
+
+impl RobotRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spawn(&mut self, x: usize, y: usize, robot_type: RobotType) -> RobotId {
+        self.insert(Robot::new(x, y, robot_type))
+    }
+
+    pub fn insert(&mut self, mut robot: Robot) -> RobotId {
+        let id = RobotId(self.next_id);
+        self.next_id += 1;
+        robot.id = id;
+        self.robots.push(robot);
+        id
+    }
+
+    pub fn remove(&mut self, id: RobotId) -> Option<Robot> {
+        let position = self.position(id)?;
+        Some(self.robots.remove(position))
+    }
+
+    pub fn get(&self, id: RobotId) -> Option<&Robot> {
+        self.robots.get(self.position(id)?)
+    }
+
+    pub fn get_mut(&mut self, id: RobotId) -> Option<&mut Robot> {
+        let position = self.position(id)?;
+        self.robots.get_mut(position)
+    }
+
+    fn position(&self, id: RobotId) -> Option<usize> {
+        self.robots.binary_search_by_key(&id, |robot| robot.id).ok()
+    }
+
+    pub fn contains(&self, id: RobotId) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.robots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.robots.is_empty()
+    }
+
+    /// Identifiants des robots vivants, dans l'ordre de création.
+    pub fn ids(&self) -> Vec<RobotId> {
+        self.iter().map(|robot| robot.id).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Robot> {
+        self.robots.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Robot> {
+        self.robots.iter_mut()
+    }
+}
+
+impl FromIterator<Robot> for RobotRegistry {
+    fn from_iter<I: IntoIterator<Item = Robot>>(robots: I) -> Self {
+        let mut registry = Self::new();
+        for robot in robots {
+            registry.insert(robot);
+        }
+        registry
+    }
+}
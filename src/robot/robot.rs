@@ -1,44 +1,146 @@
+use serde::{Deserialize, Serialize};
+use crate::knowledge::KnowledgeMap;
 use crate::map::tile::Tile;
-use super::types::RobotType;
+use super::durability::REPAIR_HP_PER_MINERAL;
+use super::stats::RobotStats;
+use super::types::{RobotId, RobotType};
+use super::upgrades::UpgradeSet;
 use std::collections::{VecDeque, HashSet};
 
-#[derive(Debug, Clone, PartialEq)]
+/// Résultat d'une tentative de déplacement vers une cible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveOutcome {
+    Moved,
+    /// Le prochain pas du chemin est bloqué ; le chemin sera recalculé
+    Blocked,
+    /// Aucun chemin n'existe vers la cible sur la carte actuelle
+    Unreachable,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RobotState {
     Idle,
     GoingToResource,
     ReturningToBase,
+    /// Extraction en cours sur la ressource ciblée
+    Mining { progress: u32, total: u32 },
+    /// Déchargement en cours à la base
+    Unloading { progress: u32, total: u32 },
+    /// En panne : immobile jusqu'à sa réparation à la base
+    Broken,
+    /// En route vers un chantier
+    GoingToSite,
+    /// Construction en cours sur le chantier
+    Building { progress: u32, total: u32 },
+    /// Relais installé : immobile, il prolonge le réseau radio
+    Deployed,
+}
+
+impl RobotState {
+    /// Nom de chaque état sans sa progression, dans l'ordre de `index`.
+    pub const KEYS: [&'static str; 9] = [
+        "idle",
+        "going_to_resource",
+        "returning_to_base",
+        "mining",
+        "unloading",
+        "broken",
+        "going_to_site",
+        "building",
+        "deployed",
+    ];
+
+    /// Position de l'état dans `KEYS`, quelle que soit sa progression.
+    pub fn index(&self) -> usize {
+        match self {
+            RobotState::Idle => 0,
+            RobotState::GoingToResource => 1,
+            RobotState::ReturningToBase => 2,
+            RobotState::Mining { .. } => 3,
+            RobotState::Unloading { .. } => 4,
+            RobotState::Broken => 5,
+            RobotState::GoingToSite => 6,
+            RobotState::Building { .. } => 7,
+            RobotState::Deployed => 8,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            RobotState::Idle => "Repos".to_string(),
+            RobotState::GoingToResource => "Vers ressource".to_string(),
+            RobotState::ReturningToBase => "Retour base".to_string(),
+            RobotState::Mining { progress, total } => format!("Extraction {}/{}", progress, total),
+            RobotState::Unloading { progress, total } => format!("Déchargement {}/{}", progress, total),
+            RobotState::Broken => "En panne".to_string(),
+            RobotState::GoingToSite => "Vers chantier".to_string(),
+            RobotState::Building { progress, total } => format!("Construction {}/{}", progress, total),
+            RobotState::Deployed => "Déployé".to_string(),
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Robot {
+    pub id: RobotId, // Attribué par le `RobotRegistry` à l'insertion
     pub x: usize,
     pub y: usize,
     pub robot_type: RobotType,
+    pub stats: RobotStats, // Caractéristiques de base, sans les améliorations
+    pub upgrades: UpgradeSet,
     pub inventory: Vec<Tile>,
     pub explored_tiles: Vec<(usize, usize, Tile)>,
     current_target: Option<(usize, usize)>,
     pub state: RobotState,
     pub path: Vec<(usize, usize)>, // Chemin calculé vers la cible
-    pub stuck_counter: u32, // Compteur pour détecter si le robot est bloqué
-    pub last_position: Option<(usize, usize)>, // Dernière position pour détecter le blocage
+    pub stuck_counter: u32, // Tentatives de déplacement consécutives sans avancer vers la cible
+    pub exploration_target: Option<(usize, usize)>, // Frontière revendiquée par un explorateur
+    pub hp: u32,
+    pub age: u64, // Ticks écoulés depuis la fabrication
+    pub rescue_target: Option<RobotId>, // Robot en panne qu'un dépanneur va chercher
+    pub towing: Option<RobotId>, // Robot en panne remorqué par un dépanneur
+    pub knowledge: KnowledgeMap, // Carte privée, dimensionnée par `GameState` à l'insertion
+    move_progress: u32, // Avancement depuis le dernier pas, en ticks sur terrain nu
+    pub path_searches: u64, // Recherches de chemin depuis la fabrication
+    pub path_search_cost: u64, // Cases explorées par ces recherches
 }
 
 impl Robot {
     pub fn new(x: usize, y: usize, robot_type: RobotType) -> Self {
         Self {
+            id: RobotId::default(),
             x,
             y,
             robot_type,
+            stats: robot_type.base_stats(),
+            hp: robot_type.base_stats().max_hp,
+            age: 0,
+            rescue_target: None,
+            towing: None,
+            knowledge: KnowledgeMap::default(),
+            upgrades: UpgradeSet::default(),
             inventory: Vec::new(),
             explored_tiles: Vec::new(),
             current_target: None,
             state: RobotState::Idle,
             path: Vec::new(),
             stuck_counter: 0,
-            last_position: None,
+            exploration_target: None,
+            move_progress: 0,
+            path_searches: 0,
+            path_search_cost: 0,
         }
     }
 
+    /// Caractéristiques de base modifiées par les améliorations installées.
+    pub fn effective_stats(&self) -> RobotStats {
+        self.upgrades.apply(self.stats)
+    }
+
+    pub fn is_cargo_full(&self) -> bool {
+        self.inventory.len() as u32 >= self.effective_stats().cargo_capacity
+    }
+
     pub fn move_to(&mut self, new_x: usize, new_y: usize) {
         self.x = new_x;
         self.y = new_y;
@@ -51,11 +153,7 @@ impl Robot {
     }
 
     pub fn can_collect(&self, tile: Tile) -> bool {
-        matches!((self.robot_type, tile), 
-            (RobotType::Miner, Tile::Mineral) |
-            (RobotType::EnergyCollector, Tile::Energy) |
-            (RobotType::Scientist, Tile::Science)
-        )
+        self.robot_type.collectable_resource() == Some(tile)
     }
 
     pub fn record_exploration(&mut self, x: usize, y: usize, tile: Tile) {
@@ -64,6 +162,10 @@ impl Robot {
         }
     }
 
+    pub fn target(&self) -> Option<(usize, usize)> {
+        self.current_target
+    }
+
     pub fn set_target(&mut self, target_x: usize, target_y: usize) {
         self.current_target = Some((target_x, target_y));
         if self.state == RobotState::Idle {
@@ -79,6 +181,125 @@ impl Robot {
         self.path.clear();
     }
 
+    /// Envoie le robot vers la case d'où il construira.
+    pub fn set_build_site(&mut self, x: usize, y: usize) {
+        self.current_target = Some((x, y));
+        self.state = RobotState::GoingToSite;
+        self.path.clear();
+    }
+
+    /// Compte un tick de déplacement et renvoie le nombre de cases que le robot peut
+    /// parcourir ce tick selon sa vitesse. Une route double l'avancement : un robot
+    /// qui fait une case par tick en fait alors deux.
+    pub fn move_steps(&mut self, on_paved: bool) -> u32 {
+        let ticks_per_tile = self.effective_stats().ticks_per_tile.max(1);
+        self.move_progress += if on_paved { 2 } else { 1 };
+        let steps = self.move_progress / ticks_per_tile;
+        self.move_progress %= ticks_per_tile;
+        steps
+    }
+
+    /// Fait avancer l'extraction d'un tick ; vrai quand elle est terminée.
+    pub fn advance_mining(&mut self) -> bool {
+        let total = self.effective_stats().mining_ticks;
+        let progress = match self.state {
+            RobotState::Mining { progress, .. } => progress + 1,
+            _ => 1,
+        };
+        if progress >= total {
+            return true;
+        }
+        self.state = RobotState::Mining { progress, total };
+        false
+    }
+
+    /// Fait avancer le déchargement d'un tick ; vrai quand il est terminé.
+    pub fn advance_unloading(&mut self) -> bool {
+        let total = self.effective_stats().unloading_ticks;
+        let progress = match self.state {
+            RobotState::Unloading { progress, .. } => progress + 1,
+            _ => 1,
+        };
+        if progress >= total {
+            return true;
+        }
+        self.state = RobotState::Unloading { progress, total };
+        false
+    }
+
+    /// Fait avancer la construction d'un tick ; vrai quand l'ouvrage est terminé.
+    pub fn advance_building(&mut self, total: u32) -> bool {
+        let progress = match self.state {
+            RobotState::Building { progress, .. } => progress + 1,
+            _ => 1,
+        };
+        if progress >= total {
+            return true;
+        }
+        self.state = RobotState::Building { progress, total };
+        false
+    }
+
+    /// Abandonne la tâche en cours et repasse au repos, sans toucher à l'inventaire.
+    pub fn abandon_task(&mut self) {
+        self.state = RobotState::Idle;
+        self.current_target = None;
+        self.path.clear();
+        self.stuck_counter = 0;
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.state == RobotState::Broken
+    }
+
+    /// Remplace les caractéristiques de base du type ; le robot repart avec tous ses points de vie.
+    pub fn set_base_stats(&mut self, stats: RobotStats) {
+        self.stats = stats;
+        self.hp = stats.max_hp;
+    }
+
+    /// Installe le robot sur place comme relais radio.
+    pub fn deploy(&mut self) {
+        self.abandon_task();
+        self.state = RobotState::Deployed;
+    }
+
+    pub fn is_deployed(&self) -> bool {
+        self.state == RobotState::Deployed
+    }
+
+    /// Vrai à partir de la moitié des points de vie perdus.
+    pub fn is_damaged(&self) -> bool {
+        self.hp <= self.stats.max_hp / 2
+    }
+
+    pub fn wear(&mut self, amount: u32) {
+        self.hp = self.hp.saturating_sub(amount);
+    }
+
+    /// Met le robot en panne et renvoie la cargaison qu'il laisse tomber.
+    pub fn break_down(&mut self) -> Vec<Tile> {
+        self.abandon_task();
+        self.state = RobotState::Broken;
+        self.exploration_target = None;
+        self.rescue_target = None;
+        self.towing = None;
+        std::mem::take(&mut self.inventory)
+    }
+
+    pub fn repair_cost(&self) -> Vec<(Tile, u32)> {
+        let damage = self.stats.max_hp - self.hp.min(self.stats.max_hp);
+        vec![(Tile::Mineral, damage.div_ceil(REPAIR_HP_PER_MINERAL).max(1))]
+    }
+
+    /// Remet le robot à neuf ; un robot en panne repasse au repos.
+    pub fn repair(&mut self) {
+        self.hp = self.stats.max_hp;
+        if self.is_broken() {
+            self.state = RobotState::Idle;
+        }
+    }
+
     pub fn is_idle(&self) -> bool {
         self.state == RobotState::Idle
     }
@@ -93,41 +314,49 @@ impl Robot {
         items
     }
 
-    pub fn move_toward(&mut self, target_x: usize, target_y: usize, map: &[Vec<Tile>]) -> bool {
-        if let Some((last_x, last_y)) = self.last_position {
-            if last_x == self.x && last_y == self.y {
-                self.stuck_counter += 1;
-            } else {
-                self.stuck_counter = 0;
-            }
-        }
-        self.last_position = Some((self.x, self.y));
-
-        if self.stuck_counter > 5 {
-            self.path.clear();
-            self.stuck_counter = 0;
-        }
-
+    pub fn move_toward(&mut self, target_x: usize, target_y: usize, map: &[Vec<Tile>]) -> MoveOutcome {
         if self.path.is_empty() {
-            self.path = self.find_path_to(target_x, target_y, map);
+            let (path, expanded) = self.find_path_to(target_x, target_y, map);
+            self.path = path;
+            self.path_searches += 1;
+            self.path_search_cost += expanded;
         }
 
-        if let Some(&(next_x, next_y)) = self.path.first() {
-            if self.can_move_to(next_x, next_y, map) {
+        match self.path.first() {
+            Some(&(next_x, next_y)) if self.can_move_to(next_x, next_y, map) => {
                 self.x = next_x;
                 self.y = next_y;
                 self.path.remove(0);
-                return true;
-            } else {
+                self.stuck_counter = 0;
+                MoveOutcome::Moved
+            }
+            Some(_) => {
                 self.path.clear();
-                return false;
+                self.stuck_counter += 1;
+                MoveOutcome::Blocked
+            }
+            None => {
+                self.stuck_counter += 1;
+                MoveOutcome::Unreachable
             }
         }
+    }
 
-        self.simple_move_toward(target_x, target_y, map)
+    /// Avance d'au plus `steps` cases vers la cible, sans la dépasser, et renvoie
+    /// l'issue du dernier pas tenté.
+    pub fn move_toward_by(&mut self, target_x: usize, target_y: usize, map: &[Vec<Tile>], steps: u32) -> MoveOutcome {
+        let mut outcome = self.move_toward(target_x, target_y, map);
+        for _ in 1..steps {
+            if outcome != MoveOutcome::Moved || (self.x, self.y) == (target_x, target_y) {
+                break;
+            }
+            outcome = self.move_toward(target_x, target_y, map);
+        }
+        outcome
     }
 
-    fn find_path_to(&self, target_x: usize, target_y: usize, map: &[Vec<Tile>]) -> Vec<(usize, usize)> {
+    /// Plus court chemin vers la cible (vide si elle est inaccessible) et nombre de cases explorées.
+    fn find_path_to(&self, target_x: usize, target_y: usize, map: &[Vec<Tile>]) -> (Vec<(usize, usize)>, u64) {
         let mut queue = VecDeque::new();
         let mut visited = HashSet::new();
         let mut came_from = std::collections::HashMap::new();
@@ -140,7 +369,9 @@ impl Robot {
         
         let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
         
+        let mut expanded = 0;
         while let Some((x, y)) = queue.pop_front() {
+            expanded += 1;
             if (x, y) == target {
                 let mut path = Vec::new();
                 let mut current = target;
@@ -151,7 +382,7 @@ impl Robot {
                 }
                 
                 path.reverse();
-                return path;
+                return (path, expanded);
             }
             
             for (dx, dy) in directions {
@@ -170,60 +401,13 @@ impl Robot {
             }
         }
         
-        Vec::new()
-    }
-
-    fn simple_move_toward(&mut self, target_x: usize, target_y: usize, map: &[Vec<Tile>]) -> bool {
-        let dx = target_x as isize - self.x as isize;
-        let dy = target_y as isize - self.y as isize;
-
-        let mut possible_moves = Vec::new();
-        
-        if dx != 0 {
-            let new_x = (self.x as isize + dx.signum()) as usize;
-            if self.can_move_to(new_x, self.y, map) {
-                possible_moves.push((new_x, self.y));
-            }
-        }
-        
-        if dy != 0 {
-            let new_y = (self.y as isize + dy.signum()) as usize;
-            if self.can_move_to(self.x, new_y, map) {
-                possible_moves.push((self.x, new_y));
-            }
-        }
-
-        if possible_moves.is_empty() {
-            let directions = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
-            for (dx, dy) in directions {
-                let new_x = (self.x as isize + dx) as usize;
-                let new_y = (self.y as isize + dy) as usize;
-                if self.can_move_to(new_x, new_y, map) {
-                    possible_moves.push((new_x, new_y));
-                }
-            }
-        }
-        
-        if let Some(&(new_x, new_y)) = possible_moves.iter()
-            .min_by_key(|&&(x, y)| {
-                let dx = target_x as isize - x as isize;
-                let dy = target_y as isize - y as isize;
-                dx * dx + dy * dy
-            }) {
-            self.x = new_x;
-            self.y = new_y;
-            return true;
-        }
-
-        false
+        (Vec::new(), expanded)
     }
 
     pub fn can_move_to(&self, x: usize, y: usize, map: &[Vec<Tile>]) -> bool {
         if y >= map.len() || x >= map[y].len() {
             return false;
         }
-        matches!(map[y][x], 
-            Tile::Empty | Tile::Energy | Tile::Mineral | Tile::Science | Tile::Base
-        )
+        map[y][x].is_walkable()
     }
 }
\ No newline at end of file
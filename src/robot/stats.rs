@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Caractéristiques d'un robot. Chaque type fournit ses valeurs par défaut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RobotStats {
+    /// Nombre de ticks nécessaires pour avancer d'une case (1 = une case par tick)
+    pub ticks_per_tile: u32,
+    /// Durée d'extraction de la ressource que le robot sait ramasser
+    pub mining_ticks: u32,
+    /// Durée du déchargement à la base
+    pub unloading_ticks: u32,
+    /// Rayon (en cases) dans lequel le robot repère le terrain et les ressources
+    pub sensor_radius: usize,
+    /// Nombre de ressources transportées avant de rentrer à la base
+    pub cargo_capacity: u32,
+    /// Points de vie d'un robot neuf ou réparé
+    pub max_hp: u32,
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod robot_tests {
-    use super::super::{Robot, RobotType, RobotState};
+    use super::super::{MoveOutcome, Robot, RobotType, RobotState};
     use crate::map::tile::Tile;
 
     #[test]
@@ -92,4 +92,343 @@ mod robot_tests {
         assert!(idle_robot.is_idle());
         assert!(!working_robot.is_idle());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_robot_move_toward() {
+        let mut robot = Robot::new(0, 0, RobotType::Miner);
+        let map = vec![vec![Tile::Empty, Tile::Empty, Tile::Mineral]];
+
+        assert_eq!(robot.move_toward(2, 0, &map), MoveOutcome::Moved);
+        assert_eq!((robot.x, robot.y), (1, 0));
+        assert_eq!(robot.stuck_counter, 0);
+    }
+
+    #[test]
+    fn test_robot_move_toward_unreachable() {
+        let mut robot = Robot::new(0, 0, RobotType::Miner);
+        let map = vec![vec![Tile::Empty, Tile::Obstacle, Tile::Mineral]];
+
+        assert_eq!(robot.move_toward(2, 0, &map), MoveOutcome::Unreachable);
+        assert_eq!((robot.x, robot.y), (0, 0));
+        assert_eq!(robot.stuck_counter, 1);
+    }
+
+    #[test]
+    fn test_robot_abandon_task() {
+        let mut robot = Robot::new(0, 0, RobotType::Miner);
+        robot.collect(Tile::Mineral);
+        robot.set_target(4, 4);
+        robot.abandon_task();
+
+        assert!(robot.is_idle());
+        assert_eq!(robot.target(), None);
+        assert_eq!(robot.inventory.len(), 1);
+    }
+
+    #[test]
+    fn test_base_stats() {
+        let explorer = RobotType::Explorer.base_stats();
+        let miner = RobotType::Miner.base_stats();
+
+        assert!(explorer.sensor_radius > miner.sensor_radius);
+        assert!(RobotType::Scientist.base_stats().sensor_radius > 0);
+        assert!(miner.ticks_per_tile > explorer.ticks_per_tile);
+        assert_eq!(Robot::new(0, 0, RobotType::Miner).stats, miner);
+    }
+
+    #[test]
+    fn test_robot_speed() {
+        let mut miner = Robot::new(0, 0, RobotType::Miner);
+        let mut explorer = Robot::new(0, 0, RobotType::Explorer);
+
+        let miner_steps: u32 = (0..6).map(|_| miner.move_steps(false)).sum();
+        let explorer_steps: u32 = (0..6).map(|_| explorer.move_steps(false)).sum();
+
+        assert_eq!(miner_steps, 6 / miner.stats.ticks_per_tile);
+        assert_eq!(explorer_steps, 6);
+
+        // Sur une route, chacun va deux fois plus vite, même le plus rapide
+        let road_steps: u32 = (0..6).map(|_| miner.move_steps(true)).sum();
+        assert_eq!(road_steps, 2 * 6 / miner.stats.ticks_per_tile);
+        assert_eq!(explorer.move_steps(true), 2);
+    }
+
+    #[test]
+    fn test_robot_move_toward_by_stops_on_target() {
+        let mut robot = Robot::new(0, 0, RobotType::Explorer);
+        let map = vec![vec![Tile::Road; 4]];
+
+        assert_eq!(robot.move_toward_by(3, 0, &map, 2), MoveOutcome::Moved);
+        assert_eq!((robot.x, robot.y), (2, 0));
+        assert_eq!(robot.move_toward_by(3, 0, &map, 2), MoveOutcome::Moved);
+        assert_eq!((robot.x, robot.y), (3, 0));
+    }
+
+    #[test]
+    fn test_robot_mining_progress() {
+        let mut robot = Robot::new(0, 0, RobotType::Miner);
+        robot.set_target(0, 0);
+        let total = robot.stats.mining_ticks;
+
+        for progress in 1..total {
+            assert!(!robot.advance_mining());
+            assert_eq!(robot.state, RobotState::Mining { progress, total });
+        }
+        assert!(robot.advance_mining());
+    }
+
+    #[test]
+    fn test_robot_unloading_progress() {
+        let mut robot = Robot::new(0, 0, RobotType::Scientist);
+        robot.set_returning_to_base(0, 0);
+        let total = robot.stats.unloading_ticks;
+
+        assert!(!robot.advance_unloading());
+        assert_eq!(robot.state.label(), format!("Déchargement 1/{}", total));
+        for _ in 2..total {
+            assert!(!robot.advance_unloading());
+        }
+        assert!(robot.advance_unloading());
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::super::{Robot, RobotId, RobotRegistry, RobotType};
+
+    #[test]
+    fn test_registry_assigns_distinct_ids() {
+        let mut registry = RobotRegistry::new();
+        let first = registry.spawn(0, 0, RobotType::Explorer);
+        let second = registry.insert(Robot::new(1, 1, RobotType::Miner));
+
+        assert_ne!(first, second);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get(second).unwrap().id, second);
+        assert_eq!(registry.get(second).unwrap().robot_type, RobotType::Miner);
+    }
+
+    #[test]
+    fn test_registry_remove_keeps_other_ids_valid() {
+        let mut registry = RobotRegistry::new();
+        let first = registry.spawn(0, 0, RobotType::Explorer);
+        let second = registry.spawn(1, 0, RobotType::Miner);
+        let third = registry.spawn(2, 0, RobotType::Scientist);
+
+        let removed = registry.remove(second).unwrap();
+
+        assert_eq!(removed.robot_type, RobotType::Miner);
+        assert!(!registry.contains(second));
+        assert!(registry.remove(second).is_none());
+        assert_eq!(registry.get(first).unwrap().x, 0);
+        assert_eq!(registry.get(third).unwrap().x, 2);
+        assert_eq!(registry.ids(), vec![first, third]);
+    }
+
+    #[test]
+    fn test_registry_never_reuses_ids() {
+        let mut registry = RobotRegistry::new();
+        let first = registry.spawn(0, 0, RobotType::Explorer);
+        registry.remove(first);
+        let second = registry.spawn(0, 0, RobotType::Explorer);
+
+        assert_ne!(first, second);
+        assert!(registry.get(first).is_none());
+        assert!(registry.get(RobotId(42)).is_none());
+    }
+
+    #[test]
+    fn test_registry_reclaims_removed_robots() {
+        let mut registry = RobotRegistry::new();
+        for _ in 0..100 {
+            let id = registry.spawn(0, 0, RobotType::Explorer);
+            registry.remove(id);
+        }
+        let kept = registry.spawn(1, 0, RobotType::Miner);
+        let last = registry.spawn(2, 0, RobotType::Scientist);
+        registry.remove(kept);
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.ids(), vec![last]);
+        assert_eq!(last, RobotId(101));
+        assert_eq!(registry.get(last).unwrap().x, 2);
+    }
+}
+
+#[cfg(test)]
+mod upgrade_tests {
+    use super::super::{Robot, RobotType, Upgrade, UpgradeSet};
+    use super::super::upgrades::MAX_UPGRADE_LEVEL;
+    use crate::map::tile::Tile;
+
+    #[test]
+    fn test_upgrade_levels_are_capped() {
+        let mut upgrades = UpgradeSet::default();
+        for _ in 0..MAX_UPGRADE_LEVEL {
+            assert!(upgrades.add(Upgrade::Cargo));
+        }
+
+        assert!(!upgrades.add(Upgrade::Cargo));
+        assert_eq!(upgrades.level(Upgrade::Cargo), MAX_UPGRADE_LEVEL);
+        assert_eq!(upgrades.level(Upgrade::Motors), 0);
+    }
+
+    #[test]
+    fn test_upgrades_modify_base_stats() {
+        let mut robot = Robot::new(0, 0, RobotType::Miner);
+        let base = robot.stats;
+        robot.upgrades.add(Upgrade::Motors);
+        robot.upgrades.add(Upgrade::Cargo);
+        robot.upgrades.add(Upgrade::Sensors);
+        robot.upgrades.add(Upgrade::Battery);
+
+        let stats = robot.effective_stats();
+        assert_eq!(stats.ticks_per_tile, base.ticks_per_tile - 1);
+        assert_eq!(stats.cargo_capacity, base.cargo_capacity + 1);
+        assert_eq!(stats.sensor_radius, base.sensor_radius + 1);
+        assert_eq!(stats.mining_ticks, base.mining_ticks - 1);
+        assert_eq!(robot.stats, base);
+    }
+
+    #[test]
+    fn test_upgrades_never_reach_zero_duration() {
+        let mut upgrades = UpgradeSet::default();
+        for _ in 0..MAX_UPGRADE_LEVEL {
+            upgrades.add(Upgrade::Motors);
+            upgrades.add(Upgrade::Battery);
+        }
+        let stats = upgrades.apply(RobotType::EnergyCollector.base_stats());
+
+        assert_eq!(stats.ticks_per_tile, 1);
+        assert_eq!(stats.unloading_ticks, 1);
+    }
+
+    #[test]
+    fn test_cargo_capacity() {
+        let mut robot = Robot::new(0, 0, RobotType::Scientist);
+        robot.upgrades.add(Upgrade::Cargo);
+        robot.collect(Tile::Science);
+
+        assert!(!robot.is_cargo_full());
+        robot.collect(Tile::Science);
+        assert!(robot.is_cargo_full());
+    }
+
+    #[test]
+    fn test_upgrade_cost_grows_with_level() {
+        assert_eq!(Upgrade::Cargo.cost(0), vec![(Tile::Mineral, 4)]);
+        assert_eq!(Upgrade::Cargo.cost(2), vec![(Tile::Mineral, 12)]);
+    }
+}
+
+#[cfg(test)]
+mod durability_tests {
+    use super::super::{Robot, RobotState, RobotStats, RobotType, WearConfig};
+    use super::super::durability::is_hazardous;
+    use crate::config::WorldConfig;
+    use crate::game::GameState;
+    use crate::map::tile::Tile;
+
+    #[test]
+    fn test_wear_and_damage() {
+        let mut robot = Robot::new(0, 0, RobotType::Explorer);
+        let max_hp = robot.stats.max_hp;
+        assert_eq!(robot.hp, max_hp);
+        assert!(!robot.is_damaged());
+
+        robot.wear(max_hp / 2);
+        assert!(robot.is_damaged());
+        robot.wear(max_hp);
+        assert_eq!(robot.hp, 0);
+
+        // Pas de débordement pour des points de vie extrêmes
+        robot.set_base_stats(RobotStats { max_hp: u32::MAX, ..robot.stats });
+        assert!(!robot.is_damaged());
+    }
+
+    #[test]
+    fn test_break_down_drops_cargo() {
+        let mut robot = Robot::new(2, 0, RobotType::Miner);
+        robot.set_target(2, 0);
+        robot.collect(Tile::Mineral);
+
+        let cargo = robot.break_down();
+        assert_eq!(cargo, vec![Tile::Mineral]);
+        assert!(robot.inventory.is_empty());
+        assert_eq!(robot.state, RobotState::Broken);
+        assert_eq!(robot.target(), None);
+    }
+
+    #[test]
+    fn test_spilled_cargo_is_collected_again() {
+        let config = WorldConfig {
+            width: 40,
+            height: 40,
+            robots: vec![(RobotType::Explorer, 1), (RobotType::Miner, 1)],
+            ..WorldConfig::default()
+        };
+        let mut game = GameState::with_config(&config).unwrap();
+        let no_wear = WearConfig { move_wear: 0, hazard_wear: 0, age_wear_interval: 0, breakdown_chance: 0.0 };
+        game.set_wear_config(no_wear);
+        while !game.get_robots().iter().any(|robot| !robot.inventory.is_empty()) {
+            assert!(game.get_tick() < 2000, "le mineur n'a rien ramassé");
+            game.update();
+        }
+
+        // Tout l'essaim tombe en panne ; le mineur renverse sa cargaison
+        let before = game.get_map().to_vec();
+        game.set_wear_config(WearConfig { breakdown_chance: 1.0, ..no_wear });
+        game.update();
+        game.set_wear_config(no_wear);
+        let spilled: Vec<(usize, usize)> = (0..before.len())
+            .flat_map(|y| (0..before[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| before[y][x] == Tile::Empty && game.get_map()[y][x] == Tile::Mineral)
+            .collect();
+        assert_eq!(spilled.len(), 1);
+
+        // Un nouveau mineur, resté à la base, est envoyé la reprendre
+        let (dock_x, dock_y) = game.get_docking_slots()[0];
+        game.spawn_robot(dock_x, dock_y, RobotType::Miner);
+        let (x, y) = spilled[0];
+        for _ in 0..300 {
+            game.update();
+        }
+        assert_eq!(game.get_map()[y][x], Tile::Empty);
+    }
+
+    #[test]
+    fn test_repair() {
+        let mut robot = Robot::new(0, 0, RobotType::Miner);
+        assert_eq!(robot.repair_cost(), vec![(Tile::Mineral, 1)]);
+
+        robot.wear(600);
+        robot.break_down();
+        assert_eq!(robot.repair_cost(), vec![(Tile::Mineral, 3)]);
+
+        robot.repair();
+        assert_eq!(robot.hp, robot.stats.max_hp);
+        assert_eq!(robot.state, RobotState::Idle);
+    }
+
+    #[test]
+    fn test_hazardous_terrain() {
+        let map = vec![
+            vec![Tile::Empty, Tile::Empty, Tile::Empty],
+            vec![Tile::Empty, Tile::Empty, Tile::Obstacle],
+        ];
+        let wear = WearConfig::default();
+
+        assert!(is_hazardous(&map, 1, 1));
+        assert!(!is_hazardous(&map, 0, 0));
+        assert_eq!(wear.move_cost(&map, 2, 0), wear.move_wear + wear.hazard_wear);
+        assert_eq!(wear.move_cost(&map, 0, 1), wear.move_wear);
+    }
+
+    #[test]
+    fn test_breakdown_probability_grows_with_damage() {
+        let wear = WearConfig { breakdown_chance: 0.01, ..WearConfig::default() };
+
+        assert_eq!(wear.breakdown_probability(100, 100), 0.01);
+        assert_eq!(wear.breakdown_probability(0, 100), 0.02);
+    }
+}
@@ -1,18 +1,87 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+use super::stats::RobotStats;
+
+/// Identifiant stable d'un robot, valable tant que le robot existe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct RobotId(pub u32);
+
+impl fmt::Display for RobotId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RobotType {
     Miner,
     EnergyCollector,
     Scientist,
     Explorer,
+    /// Remorque les robots en panne jusqu'à la base
+    Rescue,
+    /// Construit routes, ponts et balises
+    Builder,
+    /// Rapporte en gros le stock des dépôts à la base
+    Hauler,
+    /// Se déploie en relais radio fixe à la limite du réseau
+    Relay,
 }
 
 impl RobotType {
+    pub const ALL: [RobotType; 8] = [
+        RobotType::Explorer,
+        RobotType::Miner,
+        RobotType::EnergyCollector,
+        RobotType::Scientist,
+        RobotType::Rescue,
+        RobotType::Builder,
+        RobotType::Hauler,
+        RobotType::Relay,
+    ];
+
     pub fn to_char(&self) -> char {
         match self {
             RobotType::Miner => 'R', //'⛏️',
             RobotType::EnergyCollector => 'R', //'⚡',
             RobotType::Scientist => 'R', //'🔬',
             RobotType::Explorer => 'R', //'🔍',
+            RobotType::Rescue => 'R',
+            RobotType::Builder => 'R',
+            RobotType::Hauler => 'R',
+            RobotType::Relay => 'R',
+        }
+    }
+
+    /// Identifiant utilisé en ligne de commande et dans les fichiers de configuration.
+    pub fn key(&self) -> &'static str {
+        match self {
+            RobotType::Miner => "miner",
+            RobotType::EnergyCollector => "collector",
+            RobotType::Scientist => "scientist",
+            RobotType::Explorer => "explorer",
+            RobotType::Rescue => "rescue",
+            RobotType::Builder => "builder",
+            RobotType::Hauler => "hauler",
+            RobotType::Relay => "relay",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|robot_type| robot_type.key() == key)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RobotType::Miner => "Mineur",
+            RobotType::EnergyCollector => "Collecteur",
+            RobotType::Scientist => "Scientifique",
+            RobotType::Explorer => "Explorateur",
+            RobotType::Rescue => "Dépanneur",
+            RobotType::Builder => "Bâtisseur",
+            RobotType::Hauler => "Transporteur",
+            RobotType::Relay => "Relais",
         }
     }
 
@@ -22,6 +91,90 @@ impl RobotType {
             RobotType::EnergyCollector => ratatui::style::Color::Yellow,
             RobotType::Scientist => ratatui::style::Color::Magenta,
             RobotType::Explorer => ratatui::style::Color::Green,
+            RobotType::Rescue => ratatui::style::Color::LightRed,
+            RobotType::Builder => ratatui::style::Color::LightBlue,
+            RobotType::Hauler => ratatui::style::Color::LightCyan,
+            RobotType::Relay => ratatui::style::Color::White,
+        }
+    }
+
+    /// Ressource que ce type de robot sait ramasser, s'il en ramasse une.
+    pub fn collectable_resource(&self) -> Option<Tile> {
+        match self {
+            RobotType::Miner => Some(Tile::Mineral),
+            RobotType::EnergyCollector => Some(Tile::Energy),
+            RobotType::Scientist => Some(Tile::Science),
+            RobotType::Explorer | RobotType::Rescue | RobotType::Builder | RobotType::Hauler | RobotType::Relay => None,
+        }
+    }
+
+    /// Caractéristiques de base du type, avant améliorations.
+    pub fn base_stats(&self) -> RobotStats {
+        match self {
+            RobotType::Explorer => RobotStats {
+                ticks_per_tile: 1,
+                mining_ticks: 0,
+                unloading_ticks: 0,
+                sensor_radius: 6,
+                cargo_capacity: 0,
+                max_hp: 2000,
+            },
+            RobotType::Miner => RobotStats {
+                ticks_per_tile: 2,
+                mining_ticks: 5,
+                unloading_ticks: 3,
+                sensor_radius: 2,
+                cargo_capacity: 1,
+                max_hp: 3000,
+            },
+            RobotType::EnergyCollector => RobotStats {
+                ticks_per_tile: 1,
+                mining_ticks: 3,
+                unloading_ticks: 2,
+                sensor_radius: 2,
+                cargo_capacity: 1,
+                max_hp: 2500,
+            },
+            RobotType::Scientist => RobotStats {
+                ticks_per_tile: 1,
+                mining_ticks: 4,
+                unloading_ticks: 2,
+                sensor_radius: 2,
+                cargo_capacity: 1,
+                max_hp: 2200,
+            },
+            RobotType::Rescue => RobotStats {
+                ticks_per_tile: 1,
+                mining_ticks: 0,
+                unloading_ticks: 0,
+                sensor_radius: 2,
+                cargo_capacity: 0,
+                max_hp: 4000,
+            },
+            RobotType::Builder => RobotStats {
+                ticks_per_tile: 1,
+                mining_ticks: 0,
+                unloading_ticks: 0,
+                sensor_radius: 2,
+                cargo_capacity: 0,
+                max_hp: 2500,
+            },
+            RobotType::Hauler => RobotStats {
+                ticks_per_tile: 2,
+                mining_ticks: 0,
+                unloading_ticks: 4,
+                sensor_radius: 2,
+                cargo_capacity: 10,
+                max_hp: 3000,
+            },
+            RobotType::Relay => RobotStats {
+                ticks_per_tile: 1,
+                mining_ticks: 0,
+                unloading_ticks: 0,
+                sensor_radius: 3,
+                cargo_capacity: 0,
+                max_hp: 2500,
+            },
         }
     }
 }
\ No newline at end of file
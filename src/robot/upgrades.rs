@@ -0,0 +1,117 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::map::tile::Tile;
+use super::stats::RobotStats;
+use super::types::{RobotId, RobotType};
+
+// Niveau maximal de chaque amélioration
+pub const MAX_UPGRADE_LEVEL: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Upgrade {
+    /// Moteurs plus rapides : un tick de moins par case
+    Motors,
+    /// Soute plus grande : une ressource de plus par voyage
+    Cargo,
+    /// Capteurs plus puissants : une case de rayon en plus
+    Sensors,
+    /// Batterie renforcée : outils plus rapides à l'extraction et au déchargement
+    Battery,
+}
+
+impl Upgrade {
+    pub const ALL: [Upgrade; 4] = [Upgrade::Motors, Upgrade::Cargo, Upgrade::Sensors, Upgrade::Battery];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Upgrade::Motors => "Moteurs",
+            Upgrade::Cargo => "Soute",
+            Upgrade::Sensors => "Capteurs",
+            Upgrade::Battery => "Batterie",
+        }
+    }
+
+    /// Prix pour passer du niveau `level` au suivant, pour un robot.
+    pub fn cost(&self, level: u32) -> Vec<(Tile, u32)> {
+        let factor = level + 1;
+        let base_cost: &[(Tile, u32)] = match self {
+            Upgrade::Motors => &[(Tile::Energy, 3), (Tile::Mineral, 2)],
+            Upgrade::Cargo => &[(Tile::Mineral, 4)],
+            Upgrade::Sensors => &[(Tile::Science, 3), (Tile::Energy, 1)],
+            Upgrade::Battery => &[(Tile::Energy, 4), (Tile::Science, 1)],
+        };
+        base_cost.iter().map(|&(tile, amount)| (tile, amount * factor)).collect()
+    }
+}
+
+/// Niveaux d'amélioration installés sur un robot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeSet {
+    motors: u32,
+    cargo: u32,
+    sensors: u32,
+    battery: u32,
+}
+
+impl UpgradeSet {
+    pub fn level(&self, upgrade: Upgrade) -> u32 {
+        match upgrade {
+            Upgrade::Motors => self.motors,
+            Upgrade::Cargo => self.cargo,
+            Upgrade::Sensors => self.sensors,
+            Upgrade::Battery => self.battery,
+        }
+    }
+
+    /// Monte l'amélioration d'un niveau ; faux si elle est déjà au maximum.
+    pub fn add(&mut self, upgrade: Upgrade) -> bool {
+        let level = match upgrade {
+            Upgrade::Motors => &mut self.motors,
+            Upgrade::Cargo => &mut self.cargo,
+            Upgrade::Sensors => &mut self.sensors,
+            Upgrade::Battery => &mut self.battery,
+        };
+        if *level >= MAX_UPGRADE_LEVEL {
+            return false;
+        }
+        *level += 1;
+        true
+    }
+
+    /// Caractéristiques obtenues en appliquant les améliorations aux valeurs de base.
+    pub fn apply(&self, base: RobotStats) -> RobotStats {
+        RobotStats {
+            ticks_per_tile: base.ticks_per_tile.saturating_sub(self.motors).max(1),
+            mining_ticks: base.mining_ticks.saturating_sub(self.battery).max(1),
+            unloading_ticks: base.unloading_ticks.saturating_sub(self.battery).max(1),
+            sensor_radius: base.sensor_radius + self.sensors as usize,
+            cargo_capacity: base.cargo_capacity + self.cargo,
+            max_hp: base.max_hp,
+        }
+    }
+}
+
+/// Robots concernés par un achat d'amélioration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpgradeTarget {
+    Robot(RobotId),
+    /// Tous les robots du type, y compris ceux construits plus tard
+    Type(RobotType),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpgradeError {
+    UnknownRobot,
+    MaxLevel,
+    InsufficientResources,
+}
+
+impl fmt::Display for UpgradeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UpgradeError::UnknownRobot => write!(f, "robot inconnu"),
+            UpgradeError::MaxLevel => write!(f, "niveau maximal atteint"),
+            UpgradeError::InsufficientResources => write!(f, "ressources insuffisantes"),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod snapshot;
+#[cfg(test)]
+mod tests;
+
+pub use snapshot::{QUICKSAVE_PATH, SAVE_FORMAT_VERSION, SaveError, from_json, load, save, state_hash, to_json};
\ No newline at end of file
@@ -0,0 +1,92 @@
+use std::fmt;
+use std::fs;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use crate::game::GameState;
+
+/// Version du format de sauvegarde, à incrémenter à chaque changement incompatible de `GameState`.
+pub const SAVE_FORMAT_VERSION: u32 = 5;
+
+/// Fichier utilisé par la sauvegarde et le chargement rapides de l'interface.
+pub const QUICKSAVE_PATH: &str = "astro_swarm.save.json";
+
+#[derive(Serialize)]
+struct SaveFile<'a> {
+    version: u32,
+    game: &'a GameState,
+}
+
+/// Lu en premier, pour refuser une version inconnue avant de décoder la partie.
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+    #[serde(rename = "game")]
+    _game: IgnoredAny,
+}
+
+#[derive(Deserialize)]
+struct SaveContent {
+    game: GameState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveError {
+    Io { path: String, message: String },
+    /// JSON mal formé ou état de partie incomplet
+    Parse(String),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io { path, message } => write!(f, "accès à {} impossible : {}", path, message),
+            SaveError::Parse(message) => write!(f, "sauvegarde invalide : {}", message),
+            SaveError::UnsupportedVersion(version) => write!(
+                f,
+                "sauvegarde au format {} non pris en charge (format attendu : {})",
+                version, SAVE_FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+/// Instantané complet de la partie, générateur aléatoire compris.
+pub fn to_json(game: &GameState) -> String {
+    let file = SaveFile { version: SAVE_FORMAT_VERSION, game };
+    serde_json::to_string(&file).expect("l'état de la partie est toujours sérialisable")
+}
+
+pub fn from_json(text: &str) -> Result<GameState, SaveError> {
+    let header: SaveHeader = serde_json::from_str(text).map_err(|error| SaveError::Parse(error.to_string()))?;
+    if header.version != SAVE_FORMAT_VERSION {
+        return Err(SaveError::UnsupportedVersion(header.version));
+    }
+    let mut content: SaveContent = serde_json::from_str(text).map_err(|error| SaveError::Parse(error.to_string()))?;
+    content.game.restore_behaviors();
+    Ok(content.game)
+}
+
+pub fn save(game: &GameState, path: &str) -> Result<(), SaveError> {
+    fs::write(path, to_json(game))
+        .map_err(|error| SaveError::Io { path: path.to_string(), message: error.to_string() })
+}
+
+pub fn load(path: &str) -> Result<GameState, SaveError> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| SaveError::Io { path: path.to_string(), message: error.to_string() })?;
+    from_json(&text)
+}
+
+/// Empreinte de l'état complet de la partie : deux parties de même empreinte
+/// évoluent à l'identique. Les tables sont parcourues dans l'ordre de leurs clés.
+pub fn state_hash(game: &GameState) -> u64 {
+    // `serde_json::Value` range les clés des objets, quel que soit l'ordre des `HashMap`
+    let canonical = serde_json::to_value(game)
+        .expect("l'état de la partie est toujours sérialisable")
+        .to_string();
+    // FNV-1a : stable d'une compilation à l'autre, contrairement à `DefaultHasher`
+    canonical.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod save_tests {
+    use super::super::{SAVE_FORMAT_VERSION, SaveError, from_json, state_hash, to_json};
+    use crate::exploration::ExplorationStrategy;
+    use crate::game::GameState;
+    use crate::robot::RobotType;
+
+    fn run(game: &mut GameState, ticks: u32) {
+        for _ in 0..ticks {
+            game.update();
+        }
+    }
+
+    #[test]
+    fn test_loaded_game_continues_identically() {
+        let mut original = GameState::new(40, 40, 7);
+        original.set_exploration_strategy(ExplorationStrategy::RandomWalk);
+        run(&mut original, 100);
+        let _ = original.queue_robot(RobotType::Explorer);
+
+        let mut loaded = from_json(&to_json(&original)).unwrap();
+        assert_eq!(loaded.get_tick(), original.get_tick());
+        assert_eq!(state_hash(&loaded), state_hash(&original));
+
+        // La marche aléatoire et les pannes tirent dans le générateur : il doit reprendre au même point
+        for _ in 0..3 {
+            run(&mut original, 50);
+            run(&mut loaded, 50);
+            assert_eq!(state_hash(&loaded), state_hash(&original), "divergence au tick {}", original.get_tick());
+        }
+    }
+
+    #[test]
+    fn test_state_hash_tracks_changes() {
+        let mut game = GameState::new(40, 40, 7);
+        let initial = state_hash(&game);
+        assert_eq!(state_hash(&GameState::new(40, 40, 7)), initial);
+        assert_ne!(state_hash(&GameState::new(40, 40, 8)), initial);
+
+        game.update();
+        assert_ne!(state_hash(&game), initial);
+    }
+
+    #[test]
+    fn test_unknown_version_is_rejected() {
+        let game = GameState::new(30, 30, 1);
+        let current = format!("\"version\":{}", SAVE_FORMAT_VERSION);
+        let text = to_json(&game).replacen(&current, "\"version\":999", 1);
+
+        assert_eq!(from_json(&text).err(), Some(SaveError::UnsupportedVersion(999)));
+        assert!(matches!(from_json("{\"version\": 1}"), Err(SaveError::Parse(_))));
+        assert!(matches!(from_json("pas du json"), Err(SaveError::Parse(_))));
+    }
+}
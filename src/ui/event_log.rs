@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+use crate::events::{EventReceiver, GameEvent};
+
+// Événements conservés pour l'affichage
+const MAX_ENTRIES: usize = 100;
+
+/// Journal des derniers événements de la partie, affiché avec `e`.
+pub struct EventLog {
+    receiver: Option<EventReceiver>,
+    entries: VecDeque<(u64, GameEvent)>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        Self { receiver: None, entries: VecDeque::new() }
+    }
+
+    /// Branche le journal sur un abonnement, voir `GameState::subscribe`.
+    pub fn attach(&mut self, receiver: EventReceiver) {
+        self.receiver = Some(receiver);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Récupère les événements publiés depuis le dernier appel.
+    pub fn poll(&mut self) {
+        let Some(receiver) = &self.receiver else { return };
+        self.entries.extend(receiver.try_iter());
+        while self.entries.len() > MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+
+    fn color(event: &GameEvent) -> Color {
+        match event {
+            GameEvent::ResourceDiscovered { .. } => Color::Green,
+            GameEvent::ResourceAssigned { .. } => Color::Gray,
+            GameEvent::ResourcePickedUp { .. } => Color::Cyan,
+            GameEvent::CargoDelivered { .. } => Color::Yellow,
+            GameEvent::RobotStuck { .. } => Color::Red,
+            GameEvent::RobotBuilt { .. } => Color::LightBlue,
+            GameEvent::Brownout { active: true } => Color::LightRed,
+            GameEvent::Brownout { active: false } => Color::Green,
+            GameEvent::GameOver { outcome } if outcome.is_victory() => Color::LightGreen,
+            GameEvent::GameOver { .. } => Color::LightRed,
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let visible = area.height.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = self.entries
+            .iter()
+            .rev()
+            .take(visible)
+            .map(|(tick, event)| {
+                ListItem::new(Line::from(Span::styled(
+                    format!("t{} {}", tick, event),
+                    Style::default().fg(Self::color(event)),
+                )))
+            })
+            .collect();
+
+        let log = List::new(items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Événements")
+                .border_style(Style::default().fg(Color::White)));
+
+        f.render_widget(log, area);
+    }
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
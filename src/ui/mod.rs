@@ -1 +1,4 @@
-pub mod terminal;
\ No newline at end of file
+pub mod event_log;
+pub mod outcome_screen;
+pub mod terminal;
+pub mod upgrade_panel;
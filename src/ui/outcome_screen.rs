@@ -0,0 +1,88 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use crate::game::GameState;
+use crate::objectives::ObjectiveStatus;
+
+/// Écran de victoire ou de défaite, ouvert quand la partie se décide.
+pub struct OutcomeScreen {
+    pub open: bool,
+}
+
+impl OutcomeScreen {
+    pub fn new() -> Self {
+        Self { open: false }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, game: &GameState) {
+        let Some(outcome) = game.get_outcome().filter(|_| self.open) else { return };
+        let color = if outcome.outcome.is_victory() { Color::Green } else { Color::Red };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("{} au tick {}", outcome.outcome, outcome.tick),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        for progress in game.get_objective_progress() {
+            let color = match progress.status {
+                ObjectiveStatus::InProgress => Color::White,
+                ObjectiveStatus::Done => Color::Green,
+                ObjectiveStatus::Failed => Color::Red,
+            };
+            lines.push(Line::from(Span::styled(progress.to_string(), Style::default().fg(color))));
+        }
+
+        let score = outcome.score;
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Score: {}", score.total()),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+        for (label, points) in [
+            ("Issue", score.outcome),
+            ("Temps", score.time),
+            ("Efficacité", score.efficiency),
+            ("Robots perdus", score.robots_lost),
+        ] {
+            lines.push(Line::from(Span::styled(
+                format!("  {:<14}{:+}", label, points),
+                Style::default().fg(Color::White),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Entrée continuer la partie, q quitter",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let width = area.width.min(52);
+        let height = area.height.min(lines.len() as u16 + 2);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let screen = Paragraph::new(lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Fin de partie")
+                .border_style(Style::default().fg(color)));
+
+        f.render_widget(Clear, popup);
+        f.render_widget(screen, popup);
+    }
+}
+
+impl Default for OutcomeScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -9,15 +9,36 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style, Modifier},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, List, ListItem},
+    widgets::{Block, Borders, Paragraph, List, ListItem, Sparkline},
     Frame, Terminal,
 };
-use std::collections::HashMap;
 use crate::map::tile::Tile;
-use crate::robot::{Robot};
+use crate::base::fabrication::MAX_QUEUE_LENGTH;
+use crate::clock::SimClock;
+use crate::game::{DiagnosticKind, GameState};
+use crate::metrics::Metrics;
+use crate::objectives::ObjectiveStatus;
+use crate::robot::RobotState;
+use super::event_log::EventLog;
+use super::outcome_screen::OutcomeScreen;
+use super::upgrade_panel::UpgradePanel;
+
+/// Contenu de l'emplacement partagé de la barre latérale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidebarPanel {
+    Diagnostics,
+    Events,
+    Metrics,
+}
 
 pub struct AppUI {
     terminal: Terminal<CrosstermBackend<io::Stdout>>,
+    pub upgrade_panel: UpgradePanel,
+    pub outcome_screen: OutcomeScreen,
+    pub show_network: bool, // Surligne la couverture radio sur la carte
+    pub status: Option<String>, // Résultat de la dernière sauvegarde ou du dernier chargement
+    pub event_log: EventLog,
+    pub panel: SidebarPanel, // Cibles abandonnées, journal des événements ou relevés
 }
 
 impl AppUI {
@@ -27,10 +48,30 @@ impl AppUI {
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
-        Ok(Self { terminal })
+        Ok(Self {
+            terminal,
+            upgrade_panel: UpgradePanel::new(),
+            outcome_screen: OutcomeScreen::new(),
+            show_network: false,
+            status: None,
+            event_log: EventLog::new(),
+            panel: SidebarPanel::Diagnostics,
+        })
+    }
+
+    /// Affiche `panel`, ou revient aux cibles abandonnées s'il est déjà affiché.
+    pub fn toggle_panel(&mut self, panel: SidebarPanel) {
+        self.panel = if self.panel == panel { SidebarPanel::Diagnostics } else { panel };
     }
 
-    pub fn render(&mut self, map: &[Vec<Tile>], robots: &[Robot], base_resources: &HashMap<Tile, u32>) -> io::Result<()> {
+    pub fn render(&mut self, game: &GameState, clock: &SimClock, metrics: &Metrics) -> io::Result<()> {
+        let upgrade_panel = &self.upgrade_panel;
+        let outcome_screen = &self.outcome_screen;
+        let show_network = self.show_network;
+        let status = self.status.as_deref();
+        let panel = self.panel;
+        self.event_log.poll();
+        let event_log = &self.event_log;
         self.terminal.draw(|f| {
             let chunks = Layout::default()
                 .direction(Direction::Horizontal)
@@ -40,16 +81,25 @@ impl AppUI {
                     Constraint::Percentage(25),
                 ])
                 .split(f.area());
-            Self::render_map(f, chunks[0], map, robots);
-            Self::render_sidebar(f, chunks[1], base_resources);
+            Self::render_map(f, chunks[0], game, show_network, status);
+            Self::render_sidebar(f, chunks[1], game, clock, panel, event_log, metrics);
+            if upgrade_panel.open {
+                upgrade_panel.render(f, chunks[0], game);
+            }
+            outcome_screen.render(f, chunks[0], game);
         })?;
         Ok(())
     }
 
-    fn render_map(f: &mut Frame, area: Rect, map: &[Vec<Tile>], robots: &[Robot]) {
+    fn render_map(f: &mut Frame, area: Rect, game: &GameState, show_network: bool, status: Option<&str>) {
+        let map = game.get_map();
+        let robots = game.get_robots();
+        let unreachable = game.get_unreachable_positions();
+        let docks = game.get_docking_slots();
+        let network = game.get_comms_network();
         let mut display_map = map.to_vec();
         
-        for robot in robots {
+        for robot in robots.iter() {
             if robot.y < display_map.len() && robot.x < display_map[robot.y].len() {
                 display_map[robot.y][robot.x] = Tile::Robot;
             }
@@ -64,14 +114,26 @@ impl AppUI {
                     .enumerate()
                     .map(|(x, tile)| {
                         if let Some(robot) = robots.iter().find(|r| r.x == x && r.y == y) {
-                            Span::styled(
-                                format!("{} ", robot.robot_type.to_char()),
-                                Style::default().fg(robot.robot_type.color()).add_modifier(Modifier::BOLD)
-                            )
+                            // Robot en panne : X rouge ; robot endommagé : fond gris
+                            let (ch, mut style) = if robot.is_broken() {
+                                ('X', Style::default().fg(Color::Red))
+                            } else {
+                                (robot.robot_type.to_char(), Style::default().fg(robot.robot_type.color()))
+                            };
+                            if robot.is_damaged() && !robot.is_broken() {
+                                style = style.bg(Color::DarkGray);
+                            }
+                            Span::styled(format!("{} ", ch), style.add_modifier(Modifier::BOLD))
                         } else {
-                            let ch = tile.to_char();
+                            let ch = if docks.contains(&(x, y)) { 'O' } else { tile.to_char() };
                             let color = Self::get_tile_color(tile);
-                            Span::styled(format!("{} ", ch), Style::default().fg(color))
+                            let mut style = Style::default().fg(color);
+                            if unreachable.contains(&(x, y)) {
+                                style = style.bg(Color::Red);
+                            } else if show_network && network.is_covered(x, y) {
+                                style = style.bg(Color::Rgb(20, 30, 70));
+                            }
+                            Span::styled(format!("{} ", ch), style)
                         }
                     })
                     .collect();
@@ -79,27 +141,59 @@ impl AppUI {
             })
             .collect();
 
+        let title = match status {
+            Some(status) => format!("Planet Invaders - Carte - {}", status),
+            None => "Planet Invaders - Carte".to_string(),
+        };
         let map_widget = Paragraph::new(map_lines)
             .block(Block::default()
                 .borders(Borders::ALL)
-                .title("Planet Invaders - Carte")
+                .title(title)
                 .border_style(Style::default().fg(Color::Green)));
         
         f.render_widget(map_widget, area);
     }
 
-    fn render_sidebar(f: &mut Frame, area: Rect, base_resources: &HashMap<Tile, u32>) {
+    fn render_sidebar(
+        f: &mut Frame,
+        area: Rect,
+        game: &GameState,
+        clock: &SimClock,
+        panel: SidebarPanel,
+        event_log: &EventLog,
+        metrics: &Metrics,
+    ) {
+        let objectives = game.get_objective_progress();
+        let objectives_height = if objectives.is_empty() { 0 } else { objectives.len() as u16 + 2 };
         let sidebar_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(7),
+                Constraint::Length(objectives_height),
+                Constraint::Length(9),
+                Constraint::Length(5),
                 Constraint::Length(6),
-                Constraint::Percentage(50),
-                Constraint::Percentage(50),
+                Constraint::Length(7),
+                Constraint::Min(6),
+                Constraint::Length(13),
+                Constraint::Length(11),
             ])
             .split(area);
 
-        Self::render_base_resources(f, sidebar_chunks[0], base_resources);
-        let legend_chunks = [sidebar_chunks[1], sidebar_chunks[2]];
+        Self::render_base_resources(f, sidebar_chunks[0], game);
+        if !objectives.is_empty() {
+            Self::render_objectives(f, sidebar_chunks[1], game);
+        }
+        Self::render_exploration(f, sidebar_chunks[2], game, clock);
+        Self::render_allocation(f, sidebar_chunks[3], game);
+        Self::render_production(f, sidebar_chunks[4], game);
+        match panel {
+            SidebarPanel::Diagnostics => Self::render_diagnostics(f, sidebar_chunks[5], game),
+            SidebarPanel::Events => event_log.render(f, sidebar_chunks[5]),
+            SidebarPanel::Metrics => Self::render_metrics(f, sidebar_chunks[5], metrics),
+        }
+        Self::render_fleet(f, sidebar_chunks[6], game);
+        let legend_chunks = [sidebar_chunks[7], sidebar_chunks[8]];
         let tile_legend_items = vec![
             Self::create_legend_item('.', Color::DarkGray, "Terrain vide"),
             Self::create_legend_item('#', Color::Gray, "Obstacle"),
@@ -107,6 +201,11 @@ impl AppUI {
             Self::create_legend_item('M', Color::Cyan, "Minéral"),
             Self::create_legend_item('S', Color::Magenta, "Science"),
             Self::create_legend_item('B', Color::Green, "Base"),
+            Self::create_legend_item('O', Color::Green, "Quai"),
+            Self::create_legend_item('=', Color::White, "Route"),
+            Self::create_legend_item('H', Color::LightYellow, "Pont"),
+            Self::create_legend_item('^', Color::LightMagenta, "Balise"),
+            Self::create_legend_item('D', Color::LightGreen, "Dépôt"),
         ];
 
         let tile_legend = List::new(tile_legend_items)
@@ -122,6 +221,11 @@ impl AppUI {
             Self::create_legend_item('R', Color::Yellow, "Collecteur"),
             Self::create_legend_item('R', Color::Magenta, "Scientifique"),
             Self::create_legend_item('R', Color::Green, "Explorateur"),
+            Self::create_legend_item('R', Color::LightRed, "Dépanneur"),
+            Self::create_legend_item('R', Color::LightBlue, "Bâtisseur"),
+            Self::create_legend_item('R', Color::LightCyan, "Transporteur"),
+            Self::create_legend_item('R', Color::White, "Relais"),
+            Self::create_legend_item('X', Color::Red, "En panne"),
         ];
 
         let robot_legend = List::new(robot_legend_items)
@@ -133,17 +237,37 @@ impl AppUI {
         f.render_widget(robot_legend, legend_chunks[1]);
     }
     
-    fn render_base_resources(f: &mut Frame, area: Rect, resources: &HashMap<Tile, u32>) {
+    fn render_base_resources(f: &mut Frame, area: Rect, game: &GameState) {
+        let resources = game.get_base_resources();
+        let depots = game.get_depots();
+        let flow = game.get_energy_flow();
         let resource_items = vec![
             format!("Énergie: {}", resources.get(&Tile::Energy).unwrap_or(&0)),
             format!("Minéral: {}", resources.get(&Tile::Mineral).unwrap_or(&0)),
             format!("Science: {}", resources.get(&Tile::Science).unwrap_or(&0)),
+            format!("Dépôts [d]: {} (stock {})", depots.len(), depots.iter().map(|depot| depot.total()).sum::<u32>()),
         ];
         
-        let resource_lines: Vec<Line> = resource_items
+        let mut resource_lines: Vec<Line> = resource_items
             .into_iter()
             .map(|item| Line::from(Span::styled(item, Style::default().fg(Color::White))))
             .collect();
+        // Flux net d'énergie : vert si le stock monte, rouge s'il baisse
+        let flow_color = if flow > 0.0 { Color::Green } else if flow < 0.0 { Color::Red } else { Color::White };
+        let mut upkeep_line = vec![
+            Span::styled(format!("Flux: {:+.2}/tick", flow), Style::default().fg(flow_color)),
+            Span::styled(
+                format!(" (entretien {:.3})", game.get_upkeep_per_tick()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if game.is_browned_out() {
+            upkeep_line.push(Span::styled(
+                " DÉLESTAGE",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+        resource_lines.insert(1, Line::from(upkeep_line));
         
         let base_widget = Paragraph::new(resource_lines)
             .block(Block::default()
@@ -154,6 +278,230 @@ impl AppUI {
         f.render_widget(base_widget, area);
     }
 
+    fn render_objectives(f: &mut Frame, area: Rect, game: &GameState) {
+        let objective_lines: Vec<Line> = game.get_objective_progress()
+            .into_iter()
+            .map(|progress| {
+                let color = match progress.status {
+                    ObjectiveStatus::InProgress => Color::White,
+                    ObjectiveStatus::Done => Color::Green,
+                    ObjectiveStatus::Failed => Color::Red,
+                };
+                Line::from(Span::styled(progress.to_string(), Style::default().fg(color)))
+            })
+            .collect();
+
+        let title = match game.get_outcome() {
+            Some(outcome) => format!("Objectifs - {} (score {})", outcome.outcome, outcome.score.total()),
+            None => "Objectifs".to_string(),
+        };
+        let objectives_widget = Paragraph::new(objective_lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(Color::Yellow)));
+
+        f.render_widget(objectives_widget, area);
+    }
+
+    fn render_exploration(f: &mut Frame, area: Rect, game: &GameState, clock: &SimClock) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Exploration [x]")
+            .border_style(Style::default().fg(Color::Green));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let exploration_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(4), Constraint::Min(1)])
+            .split(inner);
+
+        let report_delay = match game.get_average_report_delay() {
+            Some(delay) => format!("Délai de rapport: {:.0} ticks", delay),
+            None => "Délai de rapport: -".to_string(),
+        };
+        let exploration_items = vec![
+            format!("Stratégie: {}", game.get_exploration_strategy().name()),
+            format!("Couverture: {:.1}%", game.get_coverage() * 100.0),
+            format!("Tick: {} ({})", game.get_tick(), clock.label()),
+            report_delay,
+        ];
+
+        let exploration_lines: Vec<Line> = exploration_items
+            .into_iter()
+            .map(|item| Line::from(Span::styled(item, Style::default().fg(Color::White))))
+            .collect();
+
+        f.render_widget(Paragraph::new(exploration_lines), exploration_chunks[0]);
+
+        // Couverture au fil du temps, les relevés les plus récents à droite
+        let coverage_history = game.get_coverage_history();
+        let width = exploration_chunks[1].width as usize;
+        let start = coverage_history.len().saturating_sub(width);
+        let history: Vec<u64> = coverage_history
+            .range(start..)
+            .map(|(_, value)| (value * 1000.0) as u64)
+            .collect();
+        let sparkline = Sparkline::default()
+            .data(&history)
+            .max(1000)
+            .style(Style::default().fg(Color::Green));
+
+        f.render_widget(sparkline, exploration_chunks[1]);
+    }
+
+    fn render_allocation(f: &mut Frame, area: Rect, game: &GameState) {
+        let last_cost = match game.get_allocation_history().back() {
+            Some(metrics) => format!("t{}: {} affect., coût {}", metrics.tick, metrics.assignments, metrics.total_cost),
+            None => "Aucune affectation".to_string(),
+        };
+        let average_cost = match game.get_average_assignment_cost() {
+            Some(cost) => format!("Coût moyen: {:.1} cases", cost),
+            None => "Coût moyen: -".to_string(),
+        };
+        let allocation_items = vec![
+            format!("Stratégie: {}", game.get_allocation_strategy().name()),
+            last_cost,
+            average_cost,
+        ];
+
+        let allocation_lines: Vec<Line> = allocation_items
+            .into_iter()
+            .map(|item| Line::from(Span::styled(item, Style::default().fg(Color::White))))
+            .collect();
+
+        let allocation_widget = Paragraph::new(allocation_lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Allocation [a]")
+                .border_style(Style::default().fg(Color::Green)));
+
+        f.render_widget(allocation_widget, area);
+    }
+
+    fn render_production(f: &mut Frame, area: Rect, game: &GameState) {
+        let queue = game.get_production_queue();
+        let current = match queue.current() {
+            Some((robot_type, progress, total)) => format!("En cours: {} {}/{}", robot_type.name(), progress, total),
+            None => "En cours: -".to_string(),
+        };
+        let autobuild = if game.get_autobuild().is_some() { "activée" } else { "désactivée" };
+        let production_items = vec![
+            current,
+            format!("File: {}/{}", queue.len(), MAX_QUEUE_LENGTH),
+            format!("Auto [b]: {}", autobuild),
+            format!("Chantiers [c]: {}", game.get_build_orders().len()),
+        ];
+
+        let production_lines: Vec<Line> = production_items
+            .into_iter()
+            .map(|item| Line::from(Span::styled(item, Style::default().fg(Color::White))))
+            .collect();
+
+        let production_widget = Paragraph::new(production_lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Fabrication [1-5]")
+                .border_style(Style::default().fg(Color::Green)));
+
+        f.render_widget(production_widget, area);
+    }
+
+    fn render_fleet(f: &mut Frame, area: Rect, game: &GameState) {
+        let fleet_items: Vec<ListItem> = game.get_robots()
+            .iter()
+            .map(|robot| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} {} ", robot.id, robot.robot_type.name()),
+                        Style::default().fg(robot.robot_type.color()),
+                    ),
+                    Span::styled(robot.state.label(), Style::default().fg(Color::White)),
+                    Span::styled(
+                        format!(" {}PV", robot.hp),
+                        Style::default().fg(if robot.is_damaged() { Color::Red } else { Color::DarkGray }),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let fleet = List::new(fleet_items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Flotte")
+                .border_style(Style::default().fg(Color::White)));
+
+        f.render_widget(fleet, area);
+    }
+
+    fn render_diagnostics(f: &mut Frame, area: Rect, game: &GameState) {
+        let visible = area.height.saturating_sub(2) as usize;
+        let diagnostic_items: Vec<ListItem> = game.get_diagnostics()
+            .iter()
+            .rev()
+            .take(visible)
+            .map(|diagnostic| {
+                let reason = match diagnostic.kind {
+                    DiagnosticKind::Unreachable => "inaccessible",
+                    DiagnosticKind::Stalled => "bloqué",
+                };
+                ListItem::new(Line::from(Span::styled(
+                    format!("t{} {} ({}, {}) {}", diagnostic.tick, diagnostic.robot_id, diagnostic.x, diagnostic.y, reason),
+                    Style::default().fg(Color::Red),
+                )))
+            })
+            .collect();
+
+        let diagnostics = List::new(diagnostic_items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Cibles abandonnées")
+                .border_style(Style::default().fg(Color::Red)));
+
+        f.render_widget(diagnostics, area);
+    }
+
+    fn render_metrics(f: &mut Frame, area: Rect, metrics: &Metrics) {
+        let recent = metrics.recent();
+        let metrics_items = match (recent.front(), recent.back()) {
+            (Some(first), Some(last)) => {
+                let count = recent.len() as f64;
+                let searches = recent.iter().map(|sample| sample.path_searches).sum::<u64>() as f64 / count;
+                let cost = recent.iter().map(|sample| sample.path_search_cost).sum::<u64>() as f64 / count;
+                let change = |index: usize| last.stock[index] as i64 - first.stock[index] as i64;
+                let best = match metrics.delivered_by_robot().iter().max_by_key(|&(_, total)| total) {
+                    Some((robot_id, total)) => format!("Meilleur livreur: {} ({})", robot_id, total),
+                    None => "Meilleur livreur: -".to_string(),
+                };
+                vec![
+                    format!(
+                        "Trajets: {} ({:.1} cases, {:.1} ticks)",
+                        last.trips, last.average_trip_length, last.average_trip_time
+                    ),
+                    format!("Chemins/tick: {:.2} (coût {:.0})", searches, cost),
+                    format!("Stock sur {} ticks: E{:+} M{:+} S{:+}", recent.len(), change(0), change(1), change(2)),
+                    format!("Robots au repos: {}", last.robots_by_state[RobotState::Idle.index()]),
+                    best,
+                ]
+            }
+            _ => vec!["Aucun relevé".to_string()],
+        };
+
+        let metrics_lines: Vec<Line> = metrics_items
+            .into_iter()
+            .map(|item| Line::from(Span::styled(item, Style::default().fg(Color::White))))
+            .collect();
+
+        let metrics_widget = Paragraph::new(metrics_lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Relevés [m]")
+                .border_style(Style::default().fg(Color::White)));
+
+        f.render_widget(metrics_widget, area);
+    }
+
     fn create_legend_item(symbol: char, color: Color, description: &str) -> ListItem {
         let content = Line::from(vec![
             Span::styled(
@@ -177,6 +525,10 @@ impl AppUI {
             Tile::Science => Color::Magenta,
             Tile::Base => Color::Green,
             Tile::Robot => Color::Blue,
+            Tile::Road => Color::White,
+            Tile::Bridge => Color::LightYellow,
+            Tile::Beacon => Color::LightMagenta,
+            Tile::Depot => Color::LightGreen,
         }
     }
 }
@@ -0,0 +1,146 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use crate::game::GameState;
+use crate::map::tile::Tile;
+use crate::robot::{RobotType, Upgrade, UpgradeTarget};
+
+/// Fenêtre d'achat des améliorations, ouverte avec `u`.
+pub struct UpgradePanel {
+    pub open: bool,
+    selected_target: usize,
+    message: Option<String>,
+}
+
+impl UpgradePanel {
+    pub fn new() -> Self {
+        Self { open: false, selected_target: 0, message: None }
+    }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.message = None;
+    }
+
+    /// Cibles proposées : d'abord chaque type présent, puis chaque robot.
+    fn targets(game: &GameState) -> Vec<UpgradeTarget> {
+        let robots = game.get_robots();
+        let mut targets: Vec<UpgradeTarget> = RobotType::ALL.iter()
+            .filter(|robot_type| robots.iter().any(|robot| robot.robot_type == **robot_type))
+            .map(|robot_type| UpgradeTarget::Type(*robot_type))
+            .collect();
+        targets.extend(robots.iter().map(|robot| UpgradeTarget::Robot(robot.id)));
+        targets
+    }
+
+    fn selected(&self, game: &GameState) -> Option<UpgradeTarget> {
+        let targets = Self::targets(game);
+        if targets.is_empty() {
+            return None;
+        }
+        Some(targets[self.selected_target % targets.len()])
+    }
+
+    pub fn next_target(&mut self, game: &GameState) {
+        let count = Self::targets(game).len().max(1);
+        self.selected_target = (self.selected_target + 1) % count;
+    }
+
+    pub fn previous_target(&mut self, game: &GameState) {
+        let count = Self::targets(game).len().max(1);
+        self.selected_target = (self.selected_target + count - 1) % count;
+    }
+
+    /// Achète l'amélioration pour la cible sélectionnée, renvoyée pour l'enregistrement de la partie.
+    pub fn purchase(&mut self, game: &mut GameState, upgrade: Upgrade) -> Option<UpgradeTarget> {
+        let target = self.selected(game)?;
+        self.message = Some(match game.purchase_upgrade(target, upgrade) {
+            Ok(count) => format!("{} installé sur {} robot(s)", upgrade.name(), count),
+            Err(error) => format!("Achat impossible : {}", error),
+        });
+        Some(target)
+    }
+
+    fn target_label(game: &GameState, target: UpgradeTarget) -> String {
+        match target {
+            UpgradeTarget::Type(robot_type) => format!("Tous les robots : {}", robot_type.name()),
+            UpgradeTarget::Robot(robot_id) => match game.get_robot(robot_id) {
+                Some(robot) => format!("Robot {} ({})", robot_id, robot.robot_type.name()),
+                None => format!("Robot {}", robot_id),
+            },
+        }
+    }
+
+    fn format_cost(cost: &[(Tile, u32)]) -> String {
+        cost.iter()
+            .map(|(resource, amount)| format!("{}{}", amount, resource.to_char()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, game: &GameState) {
+        let Some(target) = self.selected(game) else { return };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("< {} >", Self::target_label(game, target)),
+                Style::default().fg(Color::Green),
+            )),
+            Line::from(""),
+        ];
+
+        for (index, upgrade) in Upgrade::ALL.iter().enumerate() {
+            let level = match target {
+                UpgradeTarget::Robot(robot_id) => game.get_robot(robot_id)
+                    .map_or(0, |robot| robot.upgrades.level(*upgrade))
+                    .to_string(),
+                UpgradeTarget::Type(_) => "-".to_string(),
+            };
+            let cost = match game.upgrade_cost(target, *upgrade) {
+                Ok(cost) => Self::format_cost(&cost),
+                Err(error) => error.to_string(),
+            };
+            lines.push(Line::from(Span::styled(
+                format!("{} {:<9} niv {}  {}", index + 1, upgrade.name(), level, cost),
+                Style::default().fg(Color::White),
+            )));
+        }
+
+        lines.push(Line::from(""));
+        if let Some(message) = &self.message {
+            lines.push(Line::from(Span::styled(message.clone(), Style::default().fg(Color::Yellow))));
+        }
+        lines.push(Line::from(Span::styled(
+            "←/→ cible, 1-4 acheter, u fermer",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let width = area.width.min(52);
+        let height = area.height.min(lines.len() as u16 + 2);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let panel = Paragraph::new(lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title("Améliorations")
+                .border_style(Style::default().fg(Color::Yellow)));
+
+        f.render_widget(Clear, popup);
+        f.render_widget(panel, popup);
+    }
+}
+
+impl Default for UpgradePanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}